@@ -1,8 +1,11 @@
 use std::collections::HashMap;
 use std::error::Error;
 use std::fmt::{Debug, Display};
-use std::num::{ParseFloatError, ParseIntError};
-use std::str::{FromStr, ParseBoolError};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::configuration::{ConfigurationType, ParsedConfiguration};
 
 /* -- -- -- -- -- -- -- -- -- -- -- -- - FURTHER STRUCTURES - -- -- -- -- -- -- -- -- -- -- -- -- */
 
@@ -10,41 +13,60 @@ pub struct AvailableConfiguration {
     pub name: String,
     pub description: String,
     pub default: String,
-    pub data_type: String,
+    pub data_type: ConfigurationType,
 }
 
-#[derive(Debug)]
-pub enum SelectError {
-    ParseError(String),
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Category {
+    Environment,
+    Agent,
+    Visualiser,
+    ExitCondition,
 }
 
-impl Error for SelectError {}
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(not(feature = "piston"), allow(dead_code))]
+pub enum Capability {
+    VisualiserInputProvider,
+    VisualiserWindow,
+}
 
-impl Display for SelectError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl Capability {
+    pub fn provided_by(&self) -> Category {
         match self {
-            Self::ParseError(error) => {
-                write!(f, "ParseError occurred while selecting (\"{}\")", error)
-            }
+            Self::VisualiserInputProvider | Self::VisualiserWindow => Category::Visualiser,
         }
     }
 }
 
-impl From<ParseFloatError> for SelectError {
-    fn from(error: ParseFloatError) -> Self {
-        SelectError::ParseError(format!("{}", error))
-    }
+#[derive(Debug)]
+pub enum SelectError {
+    UnknownConfiguration(String),
+    InvalidValue {
+        name: String,
+        value: String,
+        reason: String,
+    },
 }
 
-impl From<ParseBoolError> for SelectError {
-    fn from(error: ParseBoolError) -> Self {
-        SelectError::ParseError(format!("{}", error))
-    }
-}
+impl Error for SelectError {}
 
-impl From<ParseIntError> for SelectError {
-    fn from(error: ParseIntError) -> Self {
-        SelectError::ParseError(format!("{}", error))
+impl Display for SelectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownConfiguration(name) => {
+                write!(f, "There is no configuration option called \"{}\"", name)
+            }
+            Self::InvalidValue {
+                name,
+                value,
+                reason,
+            } => write!(
+                f,
+                "The value \"{}\" is invalid for \"{}\" ({})",
+                value, name, reason
+            ),
+        }
     }
 }
 
@@ -69,179 +91,272 @@ pub trait AvailableSupportsAvailable<S: Selected<A>, A: Available<S>> {
     fn supports_available(&self) -> Vec<A>;
 }
 
-pub trait Selected<A: Available<Self>>: Sized + Debug {
-    fn corresponding_available(&self) -> A;
-}
+pub trait Compatible {
+    fn category() -> Category;
 
-/* -- -- -- -- -- -- -- -- -- -- -- -- AVAILABLE ENVIRONMENT  -- -- -- -- -- -- -- -- -- -- -- -- */
+    fn provides(&self) -> Vec<Capability>;
+    fn requires(&self) -> Vec<Capability>;
 
-#[derive(Clone, PartialEq)]
-pub enum AvailableEnvironment {
-    GymMountainCar,
-    CodeBulletAiLearnsToDrive,
+    fn is_compatible_with<O: Compatible>(&self, other: &O) -> bool {
+        let satisfies =
+            |requires: Vec<Capability>, category: Category, provides: Vec<Capability>| {
+                requires
+                    .into_iter()
+                    .filter(|capability| capability.provided_by() == category)
+                    .all(|capability| provides.contains(&capability))
+            };
+        satisfies(self.requires(), O::category(), other.provides())
+            && satisfies(other.requires(), Self::category(), self.provides())
+    }
 }
 
-impl Available<SelectedEnvironment> for AvailableEnvironment {
-    fn values() -> Vec<Self> {
-        vec![Self::GymMountainCar, Self::CodeBulletAiLearnsToDrive]
+impl<S, A, T> AvailableSupportsAvailable<S, A> for T
+where
+    S: Selected<A>,
+    A: Available<S> + Compatible,
+    T: Compatible,
+{
+    fn supports_available(&self) -> Vec<A> {
+        A::values()
+            .into_iter()
+            .filter(|available| self.is_compatible_with(available))
+            .collect()
     }
+}
 
-    fn category_headline() -> &'static str {
-        "Available Environments"
-    }
+pub trait Selected<A: Available<Self>>: Sized + Debug {
+    fn corresponding_available(&self) -> A;
+}
 
-    fn nice_name(&self) -> &'static str {
-        match *self {
-            Self::GymMountainCar => "Gym MountainCar",
-            Self::CodeBulletAiLearnsToDrive => "Code Bullet AI Learns to DRIVE",
-        }
-    }
+/* -- -- -- -- -- -- -- -- -- -- -- -- -- --   MACROS   -- -- -- -- -- -- -- -- -- -- -- -- -- -- */
+
+macro_rules! register_available {
+    (
+        $available:ident => $selected:ident {
+            category_headline: $category_headline:literal,
+            category_name: $category_name:literal,
+            values: {
+                $(
+                    $(#[$variant_meta:meta])*
+                    $variant:ident {
+                        nice_name: $nice_name:literal,
+                        long_name: $long_name:literal,
+                        short_name: $short_name:literal $(,)?
+                    }
+                ),* $(,)?
+            },
+            $($implementation:tt)*
+        }
+    ) => {
+        #[derive(Clone, PartialEq)]
+        pub enum $available {
+            $($(#[$variant_meta])* $variant,)*
+        }
+
+        impl Available<$selected> for $available {
+            fn values() -> Vec<Self> {
+                vec![$($(#[$variant_meta])* Self::$variant,)*]
+            }
 
-    fn long_name(&self) -> &'static str {
-        match *self {
-            Self::GymMountainCar => "gym_mountaincar",
-            Self::CodeBulletAiLearnsToDrive => "code_bullet_ai_learns_to_drive",
-        }
-    }
+            fn category_headline() -> &'static str {
+                $category_headline
+            }
 
-    fn short_name(&self) -> &'static str {
-        match *self {
-            Self::GymMountainCar => "g_mc",
-            Self::CodeBulletAiLearnsToDrive => "cb_drive",
-        }
-    }
+            fn nice_name(&self) -> &'static str {
+                match *self {
+                    $($(#[$variant_meta])* Self::$variant => $nice_name,)*
+                }
+            }
 
-    fn available_configurations(&self) -> Vec<AvailableConfiguration> {
-        match *self {
-            Self::GymMountainCar => vec![AvailableConfiguration {
-                name: "goal_velocity".to_string(),
-                description: "The velocity which the agent has to have at least when he reaches \
-                the flag. Because the velocity never is negative a value of 0.0 is the off-switch \
-                for this."
-                    .to_string(),
-                default: "0.0".to_string(),
-                data_type: "f64".to_string(),
-            }],
-            Self::CodeBulletAiLearnsToDrive => vec![
-                AvailableConfiguration {
-                    name: "sensor_lines_visible".to_string(),
-                    description: "Whether the given sensor lines should be drawn in the \
-                    visualiser. Sometimes it's nice to see what an agent sees."
-                        .to_string(),
-                    default: "false".to_string(),
-                    data_type: "bool".to_string(),
-                },
-                AvailableConfiguration {
-                    name: "track_visible".to_string(),
-                    description: "Whether the track should be drawn in the visualiser. This set \
-                    to false in addition to \"sensor_lines_visible\" to true simulates the view \
-                    the agent has."
-                        .to_string(),
-                    default: "true".to_string(),
-                    data_type: "bool".to_string(),
-                },
-                AvailableConfiguration {
-                    name: "car_sensor_distance".to_string(),
-                    description: "Sets the maximum distance obstacles can be detected.".to_string(),
-                    default: "750".to_string(),
-                    data_type: "f64".to_string(),
-                },
-            ],
+            fn long_name(&self) -> &'static str {
+                match *self {
+                    $($(#[$variant_meta])* Self::$variant => $long_name,)*
+                }
+            }
+
+            fn short_name(&self) -> &'static str {
+                match *self {
+                    $($(#[$variant_meta])* Self::$variant => $short_name,)*
+                }
+            }
+
+            $($implementation)*
+        }
+
+        impl FromStr for $available {
+            type Err = String;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let lower_s = s.to_lowercase();
+                Self::values()
+                    .into_iter()
+                    .find(|element| {
+                        element.nice_name().to_lowercase().eq(&lower_s)
+                            || element.long_name().to_lowercase().eq(&lower_s)
+                            || element.short_name().to_lowercase().eq(&lower_s)
+                    })
+                    .ok_or_else(|| {
+                        format!(
+                            concat!("Did not find \"{}\" in available ", $category_name, "."),
+                            lower_s
+                        )
+                    })
+            }
         }
-    }
 
-    fn select(
-        self,
-        configuration: HashMap<String, String>,
-    ) -> Result<SelectedEnvironment, SelectError> {
-        let mut configuration = configuration;
-        match self {
-            Self::GymMountainCar => Ok(SelectedEnvironment::GymMountainCar {
-                goal_velocity: configuration
-                    .remove(&"goal_velocity".to_string())
-                    .unwrap_or_else(|| "0.0".to_string())
-                    .parse::<f64>()?,
-            }),
-            Self::CodeBulletAiLearnsToDrive => Ok(SelectedEnvironment::CodeBulletAiLearnsToDrive {
-                sensor_lines_visible: configuration
-                    .remove(&"sensor_lines_visible".to_string())
-                    .unwrap_or_else(|| "false".to_string())
-                    .parse::<bool>()?,
-                track_visible: configuration
-                    .remove(&"track_visible".to_string())
-                    .unwrap_or_else(|| "true".to_string())
-                    .parse::<bool>()?,
-                car_sensor_distance: configuration
-                    .remove(&"car_sensor_distance".to_string())
-                    .unwrap_or_else(|| "750".to_string())
-                    .parse::<f64>()?,
-            }),
+        impl Selected<$available> for $selected {
+            fn corresponding_available(&self) -> $available {
+                match *self {
+                    $($(#[$variant_meta])* Self::$variant { .. } => $available::$variant,)*
+                }
+            }
         }
-    }
-}
+    };
+}
+
+macro_rules! register_compatibility {
+    (
+        $(
+            $available:ident {
+                category: $category:ident,
+                values: {
+                    $(
+                        $(#[$variant_meta:meta])*
+                        $variant:ident {
+                            $(provides: [$($provided:ident),* $(,)?],)?
+                            $(requires: [$($required:ident),* $(,)?],)?
+                        }
+                    ),* $(,)?
+                } $(,)?
+            }
+        ),* $(,)?
+    ) => {
+        $(
+            impl Compatible for $available {
+                fn category() -> Category {
+                    Category::$category
+                }
 
-impl FromStr for AvailableEnvironment {
-    type Err = String;
+                fn provides(&self) -> Vec<Capability> {
+                    match *self {
+                        $(
+                            $(#[$variant_meta])*
+                            Self::$variant => vec![$($(Capability::$provided,)*)?],
+                        )*
+                    }
+                }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let lower_s = s.to_lowercase();
-        Self::values()
-            .into_iter()
-            .find(|element| {
-                element.nice_name().to_lowercase().eq(&lower_s)
-                    || element.long_name().to_lowercase().eq(&lower_s)
-                    || element.short_name().to_lowercase().eq(&lower_s)
-            })
-            .ok_or_else(|| format!("Did not find \"{}\" in available environments.", lower_s))
-    }
+                fn requires(&self) -> Vec<Capability> {
+                    match *self {
+                        $(
+                            $(#[$variant_meta])*
+                            Self::$variant => vec![$($(Capability::$required,)*)?],
+                        )*
+                    }
+                }
+            }
+        )*
+    };
 }
 
-impl AvailableSupportsAvailable<SelectedAgent, AvailableAgent> for AvailableEnvironment {
-    fn supports_available(&self) -> Vec<AvailableAgent> {
-        match *self {
-            Self::GymMountainCar => vec![AvailableAgent::Input, AvailableAgent::Random],
-            Self::CodeBulletAiLearnsToDrive => vec![AvailableAgent::Input, AvailableAgent::Random],
-        }
-    }
-}
+/* -- -- -- -- -- -- -- -- -- -- -- -- AVAILABLE ENVIRONMENT  -- -- -- -- -- -- -- -- -- -- -- -- */
 
-impl AvailableSupportsAvailable<SelectedVisualiser, AvailableVisualiser> for AvailableEnvironment {
-    fn supports_available(&self) -> Vec<AvailableVisualiser> {
-        match *self {
-            Self::GymMountainCar => {
-                vec![AvailableVisualiser::None, AvailableVisualiser::PistonIn2d]
-            }
-            Self::CodeBulletAiLearnsToDrive => {
-                vec![AvailableVisualiser::None, AvailableVisualiser::PistonIn2d]
+register_available! {
+    AvailableEnvironment => SelectedEnvironment {
+        category_headline: "Available Environments",
+        category_name: "environments",
+        values: {
+            #[cfg(feature = "gym-envs")]
+            GymMountainCar {
+                nice_name: "Gym MountainCar",
+                long_name: "gym_mountaincar",
+                short_name: "g_mc",
+            },
+            #[cfg(feature = "code-bullet-envs")]
+            CodeBulletAiLearnsToDrive {
+                nice_name: "Code Bullet AI Learns to DRIVE",
+                long_name: "code_bullet_ai_learns_to_drive",
+                short_name: "cb_drive",
+            },
+        },
+
+        fn available_configurations(&self) -> Vec<AvailableConfiguration> {
+            match *self {
+                #[cfg(feature = "gym-envs")]
+                Self::GymMountainCar => vec![AvailableConfiguration {
+                    name: "goal_velocity".to_string(),
+                    description: "The velocity which the agent has to have at least when he reaches \
+                    the flag. Because the velocity never is negative a value of 0.0 is the off-switch \
+                    for this."
+                        .to_string(),
+                    default: "0.0".to_string(),
+                    data_type: ConfigurationType::F64 {
+                        minimum: Some(0.0),
+                        maximum: None,
+                    },
+                }],
+                #[cfg(feature = "code-bullet-envs")]
+                Self::CodeBulletAiLearnsToDrive => vec![
+                    AvailableConfiguration {
+                        name: "sensor_lines_visible".to_string(),
+                        description: "Whether the given sensor lines should be drawn in the \
+                        visualiser. Sometimes it's nice to see what an agent sees."
+                            .to_string(),
+                        default: "false".to_string(),
+                        data_type: ConfigurationType::Bool,
+                    },
+                    AvailableConfiguration {
+                        name: "track_visible".to_string(),
+                        description: "Whether the track should be drawn in the visualiser. This set \
+                        to false in addition to \"sensor_lines_visible\" to true simulates the view \
+                        the agent has."
+                            .to_string(),
+                        default: "true".to_string(),
+                        data_type: ConfigurationType::Bool,
+                    },
+                    AvailableConfiguration {
+                        name: "car_sensor_distance".to_string(),
+                        description: "Sets the maximum distance obstacles can be detected."
+                            .to_string(),
+                        default: "750".to_string(),
+                        data_type: ConfigurationType::F64 {
+                            minimum: Some(0.0),
+                            maximum: None,
+                        },
+                    },
+                ],
             }
         }
-    }
-}
 
-impl AvailableSupportsAvailable<SelectedExitCondition, AvailableExitCondition>
-    for AvailableEnvironment
-{
-    fn supports_available(&self) -> Vec<AvailableExitCondition> {
-        match *self {
-            Self::GymMountainCar => vec![
-                AvailableExitCondition::EpisodesSimulated,
-                AvailableExitCondition::VisualiserClosed,
-            ],
-            Self::CodeBulletAiLearnsToDrive => vec![
-                AvailableExitCondition::EpisodesSimulated,
-                AvailableExitCondition::VisualiserClosed,
-            ],
+        fn select(
+            self,
+            configuration: HashMap<String, String>,
+        ) -> Result<SelectedEnvironment, SelectError> {
+            let mut configuration =
+                ParsedConfiguration::parse(self.available_configurations(), configuration)?;
+            match self {
+                #[cfg(feature = "gym-envs")]
+                Self::GymMountainCar => Ok(SelectedEnvironment::GymMountainCar {
+                    goal_velocity: configuration.take("goal_velocity"),
+                }),
+                #[cfg(feature = "code-bullet-envs")]
+                Self::CodeBulletAiLearnsToDrive => Ok(SelectedEnvironment::CodeBulletAiLearnsToDrive {
+                    sensor_lines_visible: configuration.take("sensor_lines_visible"),
+                    track_visible: configuration.take("track_visible"),
+                    car_sensor_distance: configuration.take("car_sensor_distance"),
+                }),
+            }
         }
     }
 }
 
 /* -- -- -- -- -- -- -- -- -- -- -- --  SELECTED ENVIRONMENT  -- -- -- -- -- -- -- -- -- -- -- -- */
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum SelectedEnvironment {
-    GymMountainCar {
-        goal_velocity: f64,
-    },
+    #[cfg(feature = "gym-envs")]
+    GymMountainCar { goal_velocity: f64 },
+    #[cfg(feature = "code-bullet-envs")]
     CodeBulletAiLearnsToDrive {
         sensor_lines_visible: bool,
         track_visible: bool,
@@ -249,317 +364,130 @@ pub enum SelectedEnvironment {
     },
 }
 
-impl Selected<AvailableEnvironment> for SelectedEnvironment {
-    fn corresponding_available(&self) -> AvailableEnvironment {
-        match *self {
-            Self::GymMountainCar { .. } => AvailableEnvironment::GymMountainCar,
-            Self::CodeBulletAiLearnsToDrive { .. } => {
-                AvailableEnvironment::CodeBulletAiLearnsToDrive
-            }
-        }
-    }
-}
-
 /* -- -- -- -- -- -- -- -- -- -- -- -- -- AVAILABLE AGENT  -- -- -- -- -- -- -- -- -- -- -- -- -- */
 
-#[derive(Clone, PartialEq)]
-pub enum AvailableAgent {
-    Random,
-    Input,
-}
-
-impl Available<SelectedAgent> for AvailableAgent {
-    fn values() -> Vec<Self> {
-        vec![Self::Random, Self::Input]
-    }
-
-    fn category_headline() -> &'static str {
-        "Available Agents"
-    }
-
-    fn nice_name(&self) -> &'static str {
-        match *self {
-            Self::Random => "Random",
-            Self::Input => "Input",
-        }
-    }
-
-    fn long_name(&self) -> &'static str {
-        match *self {
-            Self::Random => "random",
-            Self::Input => "input",
-        }
-    }
-
-    fn short_name(&self) -> &'static str {
-        match *self {
-            Self::Random => "rand",
-            Self::Input => "inp",
-        }
-    }
-
-    fn available_configurations(&self) -> Vec<AvailableConfiguration> {
-        match *self {
-            Self::Random => vec![],
-            Self::Input => vec![],
-        }
-    }
-
-    fn select(self, _configuration: HashMap<String, String>) -> Result<SelectedAgent, SelectError> {
-        match self {
-            Self::Random => Ok(SelectedAgent::Random),
-            Self::Input => Ok(SelectedAgent::Input),
-        }
-    }
-}
-
-impl FromStr for AvailableAgent {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let lower_s = s.to_lowercase();
-        Self::values()
-            .into_iter()
-            .find(|element| {
-                element.nice_name().to_lowercase().eq(&lower_s)
-                    || element.long_name().to_lowercase().eq(&lower_s)
-                    || element.short_name().to_lowercase().eq(&lower_s)
-            })
-            .ok_or_else(|| format!("Did not find \"{}\" in available agents.", lower_s))
-    }
-}
-
-impl AvailableSupportsAvailable<SelectedEnvironment, AvailableEnvironment> for AvailableAgent {
-    fn supports_available(&self) -> Vec<AvailableEnvironment> {
-        match *self {
-            Self::Random => vec![
-                AvailableEnvironment::GymMountainCar,
-                AvailableEnvironment::CodeBulletAiLearnsToDrive,
-            ],
-            Self::Input => vec![
-                AvailableEnvironment::GymMountainCar,
-                AvailableEnvironment::CodeBulletAiLearnsToDrive,
-            ],
-        }
-    }
-}
-
-impl AvailableSupportsAvailable<SelectedVisualiser, AvailableVisualiser> for AvailableAgent {
-    fn supports_available(&self) -> Vec<AvailableVisualiser> {
-        match *self {
-            Self::Random => vec![AvailableVisualiser::None, AvailableVisualiser::PistonIn2d],
-            Self::Input => vec![AvailableVisualiser::PistonIn2d],
+register_available! {
+    AvailableAgent => SelectedAgent {
+        category_headline: "Available Agents",
+        category_name: "agents",
+        values: {
+            Random {
+                nice_name: "Random",
+                long_name: "random",
+                short_name: "rand",
+            },
+            #[cfg(feature = "piston")]
+            Input {
+                nice_name: "Input",
+                long_name: "input",
+                short_name: "inp",
+            },
+        },
+
+        fn available_configurations(&self) -> Vec<AvailableConfiguration> {
+            match *self {
+                Self::Random => vec![],
+                #[cfg(feature = "piston")]
+                Self::Input => vec![],
+            }
         }
-    }
-}
 
-impl AvailableSupportsAvailable<SelectedExitCondition, AvailableExitCondition> for AvailableAgent {
-    fn supports_available(&self) -> Vec<AvailableExitCondition> {
-        match *self {
-            Self::Random => vec![
-                AvailableExitCondition::EpisodesSimulated,
-                AvailableExitCondition::VisualiserClosed,
-            ],
-            Self::Input => vec![
-                AvailableExitCondition::EpisodesSimulated,
-                AvailableExitCondition::VisualiserClosed,
-            ],
+        fn select(self, configuration: HashMap<String, String>) -> Result<SelectedAgent, SelectError> {
+            ParsedConfiguration::parse(self.available_configurations(), configuration)?;
+            match self {
+                Self::Random => Ok(SelectedAgent::Random),
+                #[cfg(feature = "piston")]
+                Self::Input => Ok(SelectedAgent::Input),
+            }
         }
     }
 }
 
 /* -- -- -- -- -- -- -- -- -- -- -- -- --  SELECTED AGENT  -- -- -- -- -- -- -- -- -- -- -- -- -- */
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum SelectedAgent {
     Random,
+    #[cfg(feature = "piston")]
     Input,
 }
 
-impl Selected<AvailableAgent> for SelectedAgent {
-    fn corresponding_available(&self) -> AvailableAgent {
-        match *self {
-            Self::Random => AvailableAgent::Random,
-            Self::Input => AvailableAgent::Input,
-        }
-    }
-}
-
 /* -- -- -- -- -- -- -- -- -- -- -- -- AVAILABLE VISUALISER   -- -- -- -- -- -- -- -- -- -- -- -- */
 
-#[derive(Clone, PartialEq)]
-pub enum AvailableVisualiser {
-    None,
-    PistonIn2d,
-}
-
-impl Available<SelectedVisualiser> for AvailableVisualiser {
-    fn values() -> Vec<Self> {
-        vec![Self::None, Self::PistonIn2d]
-    }
-
-    fn category_headline() -> &'static str {
-        "Available Visualisers"
-    }
-
-    fn nice_name(&self) -> &'static str {
-        match *self {
-            Self::None => "None",
-            Self::PistonIn2d => "Piston in 2D",
-        }
-    }
-
-    fn long_name(&self) -> &'static str {
-        match *self {
-            Self::None => "none",
-            Self::PistonIn2d => "piston2d",
-        }
-    }
-
-    fn short_name(&self) -> &'static str {
-        match *self {
-            Self::None => "none",
-            Self::PistonIn2d => "pi2d",
-        }
-    }
-
-    fn available_configurations(&self) -> Vec<AvailableConfiguration> {
-        match *self {
-            Self::None => vec![],
-            Self::PistonIn2d => vec![
-                AvailableConfiguration {
-                    name: "window_title".to_string(),
-                    description: "Sets the window title.".to_string(),
-                    default: "Gymnarium Application".to_string(),
-                    data_type: "String".to_string(),
-                },
-                AvailableConfiguration {
-                    name: "window_dimension".to_string(),
-                    description: "Sets the window dimensions with which it should start. It's \
-                    important to specify them with the parentheses and the comma."
-                        .to_string(),
-                    default: "(640, 480)".to_string(),
-                    data_type: "(u32, u32)".to_string(),
-                },
-                AvailableConfiguration {
-                    name: "max_frames_per_second".to_string(),
-                    description: "Sets the maximum frames per second for this window.".to_string(),
-                    default: "None".to_string(),
-                    data_type: "Option<u64>".to_string(),
-                },
-            ],
-        }
-    }
-
-    fn select(
-        self,
-        configuration: HashMap<String, String>,
-    ) -> Result<SelectedVisualiser, SelectError> {
-        fn tuple_u32_u32_from_str(s: &str) -> Result<(u32, u32), String> {
-            let numbers = if s.starts_with('(') && s.ends_with(')') {
-                &s[1..s.len() - 1]
-            } else {
-                &s
+register_available! {
+    AvailableVisualiser => SelectedVisualiser {
+        category_headline: "Available Visualisers",
+        category_name: "visualisers",
+        values: {
+            None {
+                nice_name: "None",
+                long_name: "none",
+                short_name: "none",
+            },
+            #[cfg(feature = "piston")]
+            PistonIn2d {
+                nice_name: "Piston in 2D",
+                long_name: "piston2d",
+                short_name: "pi2d",
+            },
+        },
+
+        fn available_configurations(&self) -> Vec<AvailableConfiguration> {
+            match *self {
+                Self::None => vec![],
+                #[cfg(feature = "piston")]
+                Self::PistonIn2d => vec![
+                    AvailableConfiguration {
+                        name: "window_title".to_string(),
+                        description: "Sets the window title.".to_string(),
+                        default: "Gymnarium Application".to_string(),
+                        data_type: ConfigurationType::String,
+                    },
+                    AvailableConfiguration {
+                        name: "window_dimension".to_string(),
+                        description: "Sets the window dimensions with which it should start, \
+                        e.g. \"(640, 480)\" or \"640 480\"."
+                            .to_string(),
+                        default: "(640, 480)".to_string(),
+                        data_type: ConfigurationType::U32Pair,
+                    },
+                    AvailableConfiguration {
+                        name: "max_frames_per_second".to_string(),
+                        description: "Sets the maximum frames per second for this window."
+                            .to_string(),
+                        default: "None".to_string(),
+                        data_type: ConfigurationType::OptionalU64,
+                    },
+                ],
             }
-            .split(',')
-            .map(|number_string| number_string.trim().parse::<u32>())
-            .collect::<Result<Vec<u32>, ParseIntError>>()
-            .map_err(|error| format!("{}", error))?;
-            Ok((numbers[0], numbers[1]))
         }
 
-        fn option_t_from_str<T: FromStr>(s: &str) -> Result<Option<T>, <T as FromStr>::Err> {
-            if s.eq_ignore_ascii_case("none") {
-                Ok(None)
-            } else {
-                if s.starts_with("Some(") || s.starts_with("some(") {
-                    s[5..s.len() - 1].parse::<T>()
-                } else {
-                    s.parse::<T>()
-                }
-                .map(Some)
+        #[cfg_attr(not(feature = "piston"), allow(unused_mut, unused_variables))]
+        fn select(
+            self,
+            configuration: HashMap<String, String>,
+        ) -> Result<SelectedVisualiser, SelectError> {
+            let mut configuration =
+                ParsedConfiguration::parse(self.available_configurations(), configuration)?;
+            match self {
+                Self::None => Ok(SelectedVisualiser::None),
+                #[cfg(feature = "piston")]
+                Self::PistonIn2d => Ok(SelectedVisualiser::PistonIn2d {
+                    window_title: configuration.take("window_title"),
+                    window_dimension: configuration.take("window_dimension"),
+                    max_frames_per_second: configuration.take("max_frames_per_second"),
+                }),
             }
         }
-
-        let mut configuration = configuration;
-        match self {
-            Self::None => Ok(SelectedVisualiser::None),
-            Self::PistonIn2d => Ok(SelectedVisualiser::PistonIn2d {
-                window_title: configuration
-                    .remove(&"window_title".to_string())
-                    .unwrap_or_else(|| "Gymnarium Application".to_string()),
-                window_dimension: configuration
-                    .remove(&"window_dimension".to_string())
-                    .and_then(|value| tuple_u32_u32_from_str(&value).ok())
-                    .unwrap_or((640, 480)),
-                max_frames_per_second: configuration
-                    .remove(&"max_frames_per_second".to_string())
-                    .and_then(|value| option_t_from_str::<u64>(&value).ok())
-                    .unwrap_or(None),
-            }),
-        }
-    }
-}
-
-impl FromStr for AvailableVisualiser {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let lower_s = s.to_lowercase();
-        Self::values()
-            .into_iter()
-            .find(|element| {
-                element.nice_name().to_lowercase().eq(&lower_s)
-                    || element.long_name().to_lowercase().eq(&lower_s)
-                    || element.short_name().to_lowercase().eq(&lower_s)
-            })
-            .ok_or_else(|| format!("Did not find \"{}\" in available visualisers.", lower_s))
-    }
-}
-
-impl AvailableSupportsAvailable<SelectedEnvironment, AvailableEnvironment> for AvailableVisualiser {
-    fn supports_available(&self) -> Vec<AvailableEnvironment> {
-        match *self {
-            Self::None => vec![
-                AvailableEnvironment::GymMountainCar,
-                AvailableEnvironment::CodeBulletAiLearnsToDrive,
-            ],
-            Self::PistonIn2d => vec![
-                AvailableEnvironment::GymMountainCar,
-                AvailableEnvironment::CodeBulletAiLearnsToDrive,
-            ],
-        }
-    }
-}
-
-impl AvailableSupportsAvailable<SelectedAgent, AvailableAgent> for AvailableVisualiser {
-    fn supports_available(&self) -> Vec<AvailableAgent> {
-        match *self {
-            Self::None => vec![AvailableAgent::Random],
-            Self::PistonIn2d => vec![AvailableAgent::Random, AvailableAgent::Input],
-        }
-    }
-}
-
-impl AvailableSupportsAvailable<SelectedExitCondition, AvailableExitCondition>
-    for AvailableVisualiser
-{
-    fn supports_available(&self) -> Vec<AvailableExitCondition> {
-        match *self {
-            Self::None => vec![AvailableExitCondition::EpisodesSimulated],
-            Self::PistonIn2d => vec![
-                AvailableExitCondition::EpisodesSimulated,
-                AvailableExitCondition::VisualiserClosed,
-            ],
-        }
     }
 }
 
 /* -- -- -- -- -- -- -- -- -- -- -- --  SELECTED VISUALISER   -- -- -- -- -- -- -- -- -- -- -- -- */
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum SelectedVisualiser {
     None,
+    #[cfg(feature = "piston")]
     PistonIn2d {
         window_title: String,
         window_dimension: (u32, u32),
@@ -567,152 +495,112 @@ pub enum SelectedVisualiser {
     },
 }
 
-impl Selected<AvailableVisualiser> for SelectedVisualiser {
-    fn corresponding_available(&self) -> AvailableVisualiser {
-        match *self {
-            Self::None => AvailableVisualiser::None,
-            Self::PistonIn2d { .. } => AvailableVisualiser::PistonIn2d,
-        }
-    }
-}
-
 /* -- -- -- -- -- -- -- -- -- -- --  AVAILABLE EXIT CONDITION -- -- -- -- -- -- -- -- -- -- -- -- */
 
-#[derive(Clone, PartialEq)]
-pub enum AvailableExitCondition {
-    EpisodesSimulated,
-    VisualiserClosed,
-}
-
-impl Available<SelectedExitCondition> for AvailableExitCondition {
-    fn values() -> Vec<Self> {
-        vec![Self::EpisodesSimulated, Self::VisualiserClosed]
-    }
-
-    fn category_headline() -> &'static str {
-        "Available Exit Conditions"
-    }
-
-    fn nice_name(&self) -> &'static str {
-        match *self {
-            Self::EpisodesSimulated => "episodes done simulating",
-            Self::VisualiserClosed => "visualiser is closed",
-        }
-    }
-
-    fn long_name(&self) -> &'static str {
-        match *self {
-            Self::EpisodesSimulated => "episodes_done_simulating",
-            Self::VisualiserClosed => "visualiser_is_closed",
-        }
-    }
-
-    fn short_name(&self) -> &'static str {
-        match *self {
-            Self::EpisodesSimulated => "epsdone",
-            Self::VisualiserClosed => "visclosed",
-        }
-    }
-
-    fn available_configurations(&self) -> Vec<AvailableConfiguration> {
-        match *self {
-            Self::EpisodesSimulated => vec![AvailableConfiguration {
-                name: "count_of_episodes".to_string(),
-                description: "The number of episodes to run through before exiting.".to_string(),
-                default: "20".to_string(),
-                data_type: "u128".to_string(),
-            }],
-            Self::VisualiserClosed => vec![],
-        }
-    }
-
-    fn select(
-        self,
-        configuration: HashMap<String, String>,
-    ) -> Result<SelectedExitCondition, SelectError> {
-        let mut configuration = configuration;
-        match self {
-            Self::EpisodesSimulated => Ok(SelectedExitCondition::EpisodesSimulated {
-                count_of_episodes: configuration
-                    .remove(&"count_of_episodes".to_string())
-                    .unwrap_or_else(|| "20".to_string())
-                    .parse::<u128>()?,
-            }),
-            Self::VisualiserClosed => Ok(SelectedExitCondition::VisualiserClosed),
-        }
-    }
-}
-
-impl FromStr for AvailableExitCondition {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let lower_s = s.to_lowercase();
-        Self::values()
-            .into_iter()
-            .find(|element| {
-                element.nice_name().to_lowercase().eq(&lower_s)
-                    || element.long_name().to_lowercase().eq(&lower_s)
-                    || element.short_name().to_lowercase().eq(&lower_s)
-            })
-            .ok_or_else(|| format!("Did not find \"{}\" in available exit conditions.", lower_s))
-    }
-}
-
-impl AvailableSupportsAvailable<SelectedEnvironment, AvailableEnvironment>
-    for AvailableExitCondition
-{
-    fn supports_available(&self) -> Vec<AvailableEnvironment> {
-        match *self {
-            Self::EpisodesSimulated => vec![
-                AvailableEnvironment::GymMountainCar,
-                AvailableEnvironment::CodeBulletAiLearnsToDrive,
-            ],
-            Self::VisualiserClosed => vec![
-                AvailableEnvironment::GymMountainCar,
-                AvailableEnvironment::CodeBulletAiLearnsToDrive,
-            ],
-        }
-    }
-}
-
-impl AvailableSupportsAvailable<SelectedAgent, AvailableAgent> for AvailableExitCondition {
-    fn supports_available(&self) -> Vec<AvailableAgent> {
-        match *self {
-            Self::EpisodesSimulated => vec![AvailableAgent::Random, AvailableAgent::Input],
-            Self::VisualiserClosed => vec![AvailableAgent::Random, AvailableAgent::Input],
+register_available! {
+    AvailableExitCondition => SelectedExitCondition {
+        category_headline: "Available Exit Conditions",
+        category_name: "exit conditions",
+        values: {
+            EpisodesSimulated {
+                nice_name: "episodes done simulating",
+                long_name: "episodes_done_simulating",
+                short_name: "epsdone",
+            },
+            #[cfg(feature = "piston")]
+            VisualiserClosed {
+                nice_name: "visualiser is closed",
+                long_name: "visualiser_is_closed",
+                short_name: "visclosed",
+            },
+        },
+
+        fn available_configurations(&self) -> Vec<AvailableConfiguration> {
+            match *self {
+                Self::EpisodesSimulated => vec![AvailableConfiguration {
+                    name: "count_of_episodes".to_string(),
+                    description: "The number of episodes to run through before exiting.".to_string(),
+                    default: "20".to_string(),
+                    data_type: ConfigurationType::U128 {
+                        minimum: None,
+                        maximum: None,
+                    },
+                }],
+                #[cfg(feature = "piston")]
+                Self::VisualiserClosed => vec![],
+            }
         }
-    }
-}
 
-impl AvailableSupportsAvailable<SelectedVisualiser, AvailableVisualiser>
-    for AvailableExitCondition
-{
-    fn supports_available(&self) -> Vec<AvailableVisualiser> {
-        match *self {
-            Self::EpisodesSimulated => {
-                vec![AvailableVisualiser::None, AvailableVisualiser::PistonIn2d]
+        fn select(
+            self,
+            configuration: HashMap<String, String>,
+        ) -> Result<SelectedExitCondition, SelectError> {
+            let mut configuration =
+                ParsedConfiguration::parse(self.available_configurations(), configuration)?;
+            match self {
+                Self::EpisodesSimulated => Ok(SelectedExitCondition::EpisodesSimulated {
+                    count_of_episodes: configuration.take("count_of_episodes"),
+                }),
+                #[cfg(feature = "piston")]
+                Self::VisualiserClosed => Ok(SelectedExitCondition::VisualiserClosed),
             }
-            Self::VisualiserClosed => vec![AvailableVisualiser::PistonIn2d],
         }
     }
 }
 
 /* -- -- -- -- -- -- -- -- -- -- -- - SELECTED EXIT CONDITION -- -- -- -- -- -- -- -- -- -- -- -- */
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum SelectedExitCondition {
-    EpisodesSimulated { count_of_episodes: u128 },
+    EpisodesSimulated {
+        count_of_episodes: u128,
+    },
+    #[cfg(feature = "piston")]
     VisualiserClosed,
 }
 
-impl Selected<AvailableExitCondition> for SelectedExitCondition {
-    fn corresponding_available(&self) -> AvailableExitCondition {
-        match *self {
-            Self::EpisodesSimulated { .. } => AvailableExitCondition::EpisodesSimulated,
-            Self::VisualiserClosed => AvailableExitCondition::VisualiserClosed,
-        }
-    }
+/* -- -- -- -- -- -- -- -- -- -- -- -- -- - COMPATIBILITY  - -- -- -- -- -- -- -- -- -- -- -- -- -- */
+
+register_compatibility! {
+    AvailableEnvironment {
+        category: Environment,
+        values: {
+            #[cfg(feature = "gym-envs")]
+            GymMountainCar {},
+            #[cfg(feature = "code-bullet-envs")]
+            CodeBulletAiLearnsToDrive {},
+        },
+    },
+    AvailableAgent {
+        category: Agent,
+        values: {
+            Random {},
+            #[cfg(feature = "piston")]
+            Input {
+                requires: [VisualiserInputProvider],
+            },
+        },
+    },
+    AvailableVisualiser {
+        category: Visualiser,
+        values: {
+            None {},
+            #[cfg(feature = "piston")]
+            PistonIn2d {
+                provides: [VisualiserInputProvider, VisualiserWindow],
+            },
+        },
+    },
+    AvailableExitCondition {
+        category: ExitCondition,
+        values: {
+            EpisodesSimulated {},
+            #[cfg(feature = "piston")]
+            VisualiserClosed {
+                requires: [VisualiserWindow],
+            },
+        },
+    },
 }
 
 /*  -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- --  */
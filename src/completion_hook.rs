@@ -0,0 +1,70 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::resource_usage::ResourceUsage;
+use crate::run_spec::RunSpec;
+
+#[derive(Serialize)]
+struct RunSummary<'a> {
+    status: &'static str,
+    error: Option<String>,
+    wall_time_seconds: f64,
+    resource_usage: Option<ResourceUsage>,
+    run_spec: &'a RunSpec,
+}
+
+// `error` is the error the run failed with or the message it panicked with.
+pub fn run(command: &str, run_spec: &RunSpec, error: Option<String>, wall_time: Duration) {
+    let status = if error.is_none() {
+        "succeeded"
+    } else {
+        "failed"
+    };
+    let run_summary = RunSummary {
+        status,
+        error,
+        wall_time_seconds: wall_time.as_secs_f64(),
+        resource_usage: ResourceUsage::of_this_process(),
+        run_spec,
+    };
+    let run_summary = match serde_json::to_string(&run_summary) {
+        Ok(run_summary) => run_summary,
+        Err(error) => {
+            eprintln!("Warning: Could not create the run summary ({}).", error);
+            return;
+        }
+    };
+
+    let mut shell = if cfg!(windows) {
+        let mut shell = Command::new("cmd");
+        shell.arg("/C");
+        shell
+    } else {
+        let mut shell = Command::new("sh");
+        shell.arg("-c");
+        shell
+    };
+    let outcome = shell
+        .arg(command)
+        .env("GYMNARIUM_RUN_STATUS", status)
+        .stdin(Stdio::piped())
+        .spawn()
+        .and_then(|mut child| {
+            if let Some(mut stdin) = child.stdin.take() {
+                // The command may not read the summary at all, so a broken pipe is fine.
+                let _ = stdin.write_all(run_summary.as_bytes());
+            }
+            child.wait()
+        });
+    match outcome {
+        Ok(status) if status.success() => {}
+        Ok(status) => eprintln!("Warning: The --on-complete command exited with {}.", status),
+        Err(error) => eprintln!(
+            "Warning: Could not run the --on-complete command ({}).",
+            error
+        ),
+    }
+}
@@ -0,0 +1,439 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde_json::{json, Map, Value};
+
+use crate::availables::{Available, AvailableConfiguration, SelectError, Selected};
+
+/* -- -- -- -- -- -- -- -- -- -- -- -- -- CONFIGURATION TYPE  -- -- -- -- -- -- -- -- -- -- -- -- */
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigurationType {
+    #[cfg_attr(not(feature = "code-bullet-envs"), allow(dead_code))]
+    Bool,
+    F64 {
+        minimum: Option<f64>,
+        maximum: Option<f64>,
+    },
+    U128 {
+        minimum: Option<u128>,
+        maximum: Option<u128>,
+    },
+    #[cfg_attr(not(feature = "piston"), allow(dead_code))]
+    U32Pair,
+    #[cfg_attr(not(feature = "piston"), allow(dead_code))]
+    OptionalU64,
+    #[cfg_attr(not(feature = "piston"), allow(dead_code))]
+    String,
+}
+
+impl ConfigurationType {
+    pub fn parse(&self, value: &str) -> Result<ConfigurationValue, String> {
+        fn check_range<T: PartialOrd + Display>(
+            value: T,
+            minimum: &Option<T>,
+            maximum: &Option<T>,
+        ) -> Result<T, String> {
+            match (minimum, maximum) {
+                (Some(minimum), _) if value < *minimum => {
+                    Err(format!("{} is less than the minimum of {}", value, minimum))
+                }
+                (_, Some(maximum)) if value > *maximum => Err(format!(
+                    "{} is greater than the maximum of {}",
+                    value, maximum
+                )),
+                _ => Ok(value),
+            }
+        }
+
+        fn parse_trimmed<T: FromStr>(value: &str) -> Result<T, String>
+        where
+            <T as FromStr>::Err: Display,
+        {
+            value
+                .trim()
+                .parse::<T>()
+                .map_err(|error| format!("{}", error))
+        }
+
+        // Accepts a decimal comma like "0,5" as long as it can't be confused with a thousands
+        // separator like in "1,000" or "1.000,5".
+        fn normalize_decimal_separator(value: &str) -> Result<String, String> {
+            let trimmed_value = value.trim();
+            match trimmed_value.split_once(',') {
+                None => Ok(value.to_string()),
+                Some(_)
+                    if trimmed_value.contains('.') || trimmed_value.matches(',').count() > 1 =>
+                {
+                    Err(format!(
+                        "\"{}\" mixes separators, use '.' as the only decimal separator",
+                        trimmed_value
+                    ))
+                }
+                Some((_, fraction))
+                    if fraction.len() == 3 && fraction.chars().all(|c| c.is_ascii_digit()) =>
+                {
+                    Err(format!(
+                        "\"{}\" is ambiguous because ',' could separate thousands, use '.' as \
+                        the decimal separator",
+                        trimmed_value
+                    ))
+                }
+                Some(_) => Ok(trimmed_value.replace(',', ".")),
+            }
+        }
+
+        match self {
+            Self::Bool => parse_trimmed::<bool>(value).map(ConfigurationValue::Bool),
+            Self::F64 { minimum, maximum } => normalize_decimal_separator(value)
+                .and_then(|value| parse_trimmed::<f64>(&value))
+                // NaN compares false with everything and would pass any range.
+                .and_then(|value| {
+                    if value.is_nan() {
+                        Err("NaN is not a valid number".to_string())
+                    } else {
+                        Ok(value)
+                    }
+                })
+                .and_then(|value| check_range(value, minimum, maximum))
+                .map(ConfigurationValue::F64),
+            Self::U128 { minimum, maximum } => parse_trimmed::<u128>(value)
+                .and_then(|value| check_range(value, minimum, maximum))
+                .map(ConfigurationValue::U128),
+            Self::U32Pair => {
+                let value = value.trim();
+                let value = if value.starts_with('(') && value.ends_with(')') {
+                    &value[1..value.len() - 1]
+                } else {
+                    value
+                };
+                let numbers = if value.contains(',') {
+                    value.split(',').collect::<Vec<&str>>()
+                } else {
+                    value.split_whitespace().collect::<Vec<&str>>()
+                }
+                .into_iter()
+                .map(parse_trimmed::<u32>)
+                .collect::<Result<Vec<u32>, String>>()?;
+                if numbers.len() == 2 {
+                    Ok(ConfigurationValue::U32Pair(numbers[0], numbers[1]))
+                } else {
+                    Err(format!("expected 2 numbers but got {}", numbers.len()))
+                }
+            }
+            Self::OptionalU64 => {
+                let value = value.trim();
+                if value.eq_ignore_ascii_case("none") {
+                    Ok(ConfigurationValue::OptionalU64(None))
+                } else if (value.starts_with("Some(") || value.starts_with("some("))
+                    && value.ends_with(')')
+                {
+                    parse_trimmed::<u64>(&value[5..value.len() - 1])
+                        .map(|value| ConfigurationValue::OptionalU64(Some(value)))
+                } else {
+                    parse_trimmed::<u64>(value)
+                        .map(|value| ConfigurationValue::OptionalU64(Some(value)))
+                }
+            }
+            Self::String => Ok(ConfigurationValue::String(value.to_string())),
+        }
+    }
+
+    pub fn json_schema(&self) -> Value {
+        fn with_range<T: Into<Value> + Clone>(
+            mut schema: Value,
+            minimum: &Option<T>,
+            maximum: &Option<T>,
+        ) -> Value {
+            if let Some(minimum) = minimum {
+                schema["minimum"] = minimum.clone().into();
+            }
+            if let Some(maximum) = maximum {
+                schema["maximum"] = maximum.clone().into();
+            }
+            schema
+        }
+
+        match self {
+            Self::Bool => json!({ "type": "boolean" }),
+            Self::F64 { minimum, maximum } => {
+                with_range(json!({ "type": "number" }), minimum, maximum)
+            }
+            Self::U128 { minimum, maximum } => with_range(
+                json!({ "type": "integer", "minimum": 0 }),
+                &minimum.map(|minimum| minimum as u64),
+                &maximum.map(|maximum| maximum as u64),
+            ),
+            Self::U32Pair => json!({
+                "type": "array",
+                "items": { "type": "integer", "minimum": 0 },
+                "minItems": 2,
+                "maxItems": 2
+            }),
+            Self::OptionalU64 => json!({ "type": ["integer", "null"], "minimum": 0 }),
+            Self::String => json!({ "type": "string" }),
+        }
+    }
+}
+
+impl Display for ConfigurationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn write_range<T: Display>(
+            f: &mut std::fmt::Formatter<'_>,
+            minimum: &Option<T>,
+            maximum: &Option<T>,
+        ) -> std::fmt::Result {
+            match (minimum, maximum) {
+                (Some(minimum), Some(maximum)) => write!(f, " in {}..={}", minimum, maximum),
+                (Some(minimum), None) => write!(f, " >= {}", minimum),
+                (None, Some(maximum)) => write!(f, " <= {}", maximum),
+                (None, None) => Ok(()),
+            }
+        }
+
+        match self {
+            Self::Bool => write!(f, "bool"),
+            Self::F64 { minimum, maximum } => {
+                write!(f, "f64")?;
+                write_range(f, minimum, maximum)
+            }
+            Self::U128 { minimum, maximum } => {
+                write!(f, "u128")?;
+                write_range(f, minimum, maximum)
+            }
+            Self::U32Pair => write!(f, "(u32, u32)"),
+            Self::OptionalU64 => write!(f, "Option<u64>"),
+            Self::String => write!(f, "String"),
+        }
+    }
+}
+
+/* -- -- -- -- -- -- -- -- -- -- -- -- - CONFIGURATION VALUE  -- -- -- -- -- -- -- -- -- -- -- -- */
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigurationValue {
+    Bool(bool),
+    F64(f64),
+    U128(u128),
+    U32Pair(u32, u32),
+    OptionalU64(Option<u64>),
+    String(String),
+}
+
+impl ConfigurationValue {
+    pub fn to_json(&self) -> Value {
+        match self {
+            Self::Bool(value) => json!(value),
+            Self::F64(value) => json!(value),
+            Self::U128(value) => json!(*value as u64),
+            Self::U32Pair(first, second) => json!([first, second]),
+            Self::OptionalU64(value) => json!(value),
+            Self::String(value) => json!(value),
+        }
+    }
+}
+
+pub trait FromConfigurationValue: Sized {
+    fn from_configuration_value(value: ConfigurationValue) -> Option<Self>;
+}
+
+impl FromConfigurationValue for bool {
+    fn from_configuration_value(value: ConfigurationValue) -> Option<Self> {
+        match value {
+            ConfigurationValue::Bool(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl FromConfigurationValue for f64 {
+    fn from_configuration_value(value: ConfigurationValue) -> Option<Self> {
+        match value {
+            ConfigurationValue::F64(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl FromConfigurationValue for u128 {
+    fn from_configuration_value(value: ConfigurationValue) -> Option<Self> {
+        match value {
+            ConfigurationValue::U128(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl FromConfigurationValue for (u32, u32) {
+    fn from_configuration_value(value: ConfigurationValue) -> Option<Self> {
+        match value {
+            ConfigurationValue::U32Pair(first, second) => Some((first, second)),
+            _ => None,
+        }
+    }
+}
+
+impl FromConfigurationValue for Option<u64> {
+    fn from_configuration_value(value: ConfigurationValue) -> Option<Self> {
+        match value {
+            ConfigurationValue::OptionalU64(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl FromConfigurationValue for String {
+    fn from_configuration_value(value: ConfigurationValue) -> Option<Self> {
+        match value {
+            ConfigurationValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/* -- -- -- -- -- -- -- -- -- -- -- -- - PARSED CONFIGURATION - -- -- -- -- -- -- -- -- -- -- -- -- */
+
+pub struct ParsedConfiguration {
+    values: HashMap<String, ConfigurationValue>,
+}
+
+impl ParsedConfiguration {
+    pub fn parse(
+        available_configurations: Vec<AvailableConfiguration>,
+        configuration: HashMap<String, String>,
+    ) -> Result<Self, SelectError> {
+        let mut configuration = configuration;
+        let mut values = HashMap::new();
+        for AvailableConfiguration {
+            name,
+            default,
+            data_type,
+            ..
+        } in available_configurations
+        {
+            let value = configuration.remove(&name).unwrap_or(default);
+            let parsed_value =
+                data_type
+                    .parse(&value)
+                    .map_err(|reason| SelectError::InvalidValue {
+                        name: name.clone(),
+                        value,
+                        reason,
+                    })?;
+            values.insert(name, parsed_value);
+        }
+        match configuration.into_iter().next() {
+            Some((name, _)) => Err(SelectError::UnknownConfiguration(name)),
+            None => Ok(Self { values }),
+        }
+    }
+
+    pub fn take<T: FromConfigurationValue>(&mut self, name: &str) -> T {
+        self.values
+            .remove(name)
+            .and_then(T::from_configuration_value)
+            .unwrap_or_else(|| {
+                panic!(
+                    "The configuration \"{}\" is not declared with the requested type.",
+                    name
+                )
+            })
+    }
+}
+
+/* -- -- -- -- -- -- -- -- -- -- -- -- -- --   SCHEMA   -- -- -- -- -- -- -- -- -- -- -- -- -- -- */
+
+pub fn json_schema_of<S: Selected<A>, A: Available<S>>() -> Value {
+    let mut availables = Map::new();
+    for available in A::values() {
+        let mut properties = Map::new();
+        for available_configuration in available.available_configurations() {
+            let mut schema = available_configuration.data_type.json_schema();
+            schema["description"] = json!(available_configuration.description);
+            if let Ok(default) = available_configuration
+                .data_type
+                .parse(&available_configuration.default)
+            {
+                schema["default"] = default.to_json();
+            }
+            properties.insert(available_configuration.name, schema);
+        }
+        availables.insert(
+            available.long_name().to_string(),
+            json!({
+                "title": available.nice_name(),
+                "type": "object",
+                "properties": properties,
+                "additionalProperties": false
+            }),
+        );
+    }
+    Value::Object(availables)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const F64: ConfigurationType = ConfigurationType::F64 {
+        minimum: None,
+        maximum: None,
+    };
+
+    #[test]
+    fn accepts_decimal_comma() {
+        assert_eq!(F64.parse("0,5"), Ok(ConfigurationValue::F64(0.5)));
+        assert_eq!(F64.parse(" -1,25 "), Ok(ConfigurationValue::F64(-1.25)));
+        assert_eq!(F64.parse("0.5"), Ok(ConfigurationValue::F64(0.5)));
+    }
+
+    #[test]
+    fn rejects_nan() {
+        assert!(F64.parse("NaN").is_err());
+        assert!(ConfigurationType::F64 {
+            minimum: Some(0.0),
+            maximum: Some(1.0),
+        }
+        .parse("nan")
+        .is_err());
+    }
+
+    #[test]
+    fn rejects_comma_which_could_separate_thousands() {
+        assert!(F64.parse("1,000").is_err());
+        assert!(F64.parse("1,000,000").is_err());
+    }
+
+    #[test]
+    fn rejects_mixed_separators() {
+        assert!(F64.parse("1.000,5").is_err());
+        assert!(F64.parse("1,000.5").is_err());
+    }
+
+    #[test]
+    fn parses_u32_pair_forms() {
+        for value in [
+            "800,600",
+            "(800, 600)",
+            "800 600",
+            " 800   600 ",
+            "(800 600)",
+            "800\t600",
+        ] {
+            assert_eq!(
+                ConfigurationType::U32Pair.parse(value),
+                Ok(ConfigurationValue::U32Pair(800, 600)),
+                "{:?}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_u32_pair_with_wrong_count() {
+        assert!(ConfigurationType::U32Pair.parse("800").is_err());
+        assert!(ConfigurationType::U32Pair.parse("800 600 400").is_err());
+        assert!(ConfigurationType::U32Pair.parse("800,600,400").is_err());
+    }
+}
@@ -0,0 +1,281 @@
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt::Display;
+
+// configuration := entry? (';' entry?)*
+// entry         := key '=' value
+// key           := (unescaped char except '=' and ';' | escaped char)+
+// value         := (unescaped char except ';' | escaped char)*
+// escaped char  := '\' any char
+
+/* -- -- -- -- -- -- -- -- -- -- -- -- -- --   ERRORS   -- -- -- -- -- -- -- -- -- -- -- -- -- -- */
+
+#[derive(Debug, PartialEq)]
+pub enum ConfigurationStringErrorKind {
+    MissingEquals(String),
+    EmptyKey,
+    DuplicateKey(String),
+    DanglingEscape,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ConfigurationStringError {
+    pub position: usize,
+    pub kind: ConfigurationStringErrorKind,
+}
+
+impl Error for ConfigurationStringError {}
+
+impl Display for ConfigurationStringError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            ConfigurationStringErrorKind::MissingEquals(key) => write!(
+                f,
+                "Expected '=' after \"{}\" at position {}",
+                key, self.position
+            ),
+            ConfigurationStringErrorKind::EmptyKey => {
+                write!(f, "Expected a key at position {}", self.position)
+            }
+            ConfigurationStringErrorKind::DuplicateKey(key) => write!(
+                f,
+                "The key \"{}\" at position {} was already given before",
+                key, self.position
+            ),
+            ConfigurationStringErrorKind::DanglingEscape => write!(
+                f,
+                "Expected a character after '\\' at position {}",
+                self.position
+            ),
+        }
+    }
+}
+
+/* -- -- -- -- -- -- -- -- -- -- -- -- -- --   PARSE    -- -- -- -- -- -- -- -- -- -- -- -- -- -- */
+
+pub fn parse(
+    configuration_string: &str,
+) -> Result<HashMap<String, String>, ConfigurationStringError> {
+    struct Entry {
+        key: String,
+        key_position: usize,
+        value: Option<String>,
+    }
+
+    fn finish_entry(
+        output: &mut HashMap<String, String>,
+        entry: Entry,
+        position: usize,
+    ) -> Result<(), ConfigurationStringError> {
+        match entry.value {
+            None if entry.key.is_empty() => Ok(()),
+            None => Err(ConfigurationStringError {
+                position,
+                kind: ConfigurationStringErrorKind::MissingEquals(entry.key),
+            }),
+            Some(_) if entry.key.is_empty() => Err(ConfigurationStringError {
+                position: entry.key_position,
+                kind: ConfigurationStringErrorKind::EmptyKey,
+            }),
+            Some(_) if output.contains_key(&entry.key) => Err(ConfigurationStringError {
+                position: entry.key_position,
+                kind: ConfigurationStringErrorKind::DuplicateKey(entry.key),
+            }),
+            Some(value) => {
+                output.insert(entry.key, value);
+                Ok(())
+            }
+        }
+    }
+
+    let mut output = HashMap::new();
+    let mut entry = Entry {
+        key: String::new(),
+        key_position: 0,
+        value: None,
+    };
+    let mut escape_position = None;
+
+    for (position, c) in configuration_string.chars().enumerate() {
+        let escaped = escape_position.take().is_some();
+        if !escaped && c == '\\' {
+            escape_position = Some(position);
+        } else if !escaped && c == ';' {
+            let next_entry = Entry {
+                key: String::new(),
+                key_position: position + 1,
+                value: None,
+            };
+            finish_entry(
+                &mut output,
+                std::mem::replace(&mut entry, next_entry),
+                position,
+            )?;
+        } else if !escaped && c == '=' && entry.value.is_none() {
+            entry.value = Some(String::new());
+        } else if let Some(value) = &mut entry.value {
+            value.push(c);
+        } else {
+            entry.key.push(c);
+        }
+    }
+
+    if let Some(position) = escape_position {
+        return Err(ConfigurationStringError {
+            position,
+            kind: ConfigurationStringErrorKind::DanglingEscape,
+        });
+    }
+    finish_entry(&mut output, entry, configuration_string.chars().count())?;
+
+    Ok(output)
+}
+
+/* -- -- -- -- -- -- -- -- -- -- -- -- -- -- SERIALIZE  -- -- -- -- -- -- -- -- -- -- -- -- -- -- */
+
+pub fn serialize(configuration: &HashMap<String, String>) -> String {
+    fn escape(string: &str, special_characters: &[char]) -> String {
+        let mut escaped = String::with_capacity(string.len());
+        for c in string.chars() {
+            if c == '\\' || special_characters.contains(&c) {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        escaped
+    }
+
+    configuration
+        .iter()
+        .collect::<BTreeMap<_, _>>()
+        .into_iter()
+        .map(|(key, value)| format!("{}={}", escape(key, &['=', ';']), escape(value, &[';'])))
+        .collect::<Vec<String>>()
+        .join(";")
+}
+
+/*  -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- -- --  */
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn configuration(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    fn error(position: usize, kind: ConfigurationStringErrorKind) -> ConfigurationStringError {
+        ConfigurationStringError { position, kind }
+    }
+
+    proptest! {
+        #[test]
+        fn parse_reverses_serialize(
+            configuration in prop::collection::hash_map(
+                "[a-z=;\\\\äß€ ]{1,8}",
+                "[a-z=;\\\\äß€ ]{0,8}",
+                0..6,
+            )
+        ) {
+            prop_assert_eq!(parse(&serialize(&configuration)), Ok(configuration));
+        }
+    }
+
+    #[test]
+    fn parses_escaped_special_characters() {
+        assert_eq!(
+            parse(r"a\=b=c\;d;e=f=g;h=\\"),
+            Ok(configuration(&[("a=b", "c;d"), ("e", "f=g"), ("h", "\\")]))
+        );
+    }
+
+    #[test]
+    fn serializes_sorted_and_escaped() {
+        assert_eq!(
+            serialize(&configuration(&[("b", "x;y"), ("a=", "\\")])),
+            r"a\==\\;b=x\;y"
+        );
+    }
+
+    #[test]
+    fn accepts_empty_segments() {
+        assert_eq!(parse(""), Ok(HashMap::new()));
+        assert_eq!(parse(";;k=v;;"), Ok(configuration(&[("k", "v")])));
+    }
+
+    #[test]
+    fn accepts_empty_values() {
+        assert_eq!(parse("k="), Ok(configuration(&[("k", "")])));
+    }
+
+    #[test]
+    fn reports_missing_equals() {
+        assert_eq!(
+            parse("goal_velocity"),
+            Err(error(
+                13,
+                ConfigurationStringErrorKind::MissingEquals("goal_velocity".to_string())
+            ))
+        );
+        assert_eq!(
+            parse("a=1;b;c=2"),
+            Err(error(
+                5,
+                ConfigurationStringErrorKind::MissingEquals("b".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn reports_empty_key() {
+        assert_eq!(
+            parse("=1"),
+            Err(error(0, ConfigurationStringErrorKind::EmptyKey))
+        );
+        assert_eq!(
+            parse("a=1;=2"),
+            Err(error(4, ConfigurationStringErrorKind::EmptyKey))
+        );
+    }
+
+    #[test]
+    fn reports_duplicate_key() {
+        assert_eq!(
+            parse("a=1;a=2"),
+            Err(error(
+                4,
+                ConfigurationStringErrorKind::DuplicateKey("a".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn reports_dangling_escape() {
+        assert_eq!(
+            parse("x\\"),
+            Err(error(1, ConfigurationStringErrorKind::DanglingEscape))
+        );
+        assert_eq!(
+            parse("k=v\\"),
+            Err(error(3, ConfigurationStringErrorKind::DanglingEscape))
+        );
+    }
+
+    #[test]
+    fn counts_positions_in_characters() {
+        assert_eq!(
+            parse("ä=1;ä=2"),
+            Err(error(
+                4,
+                ConfigurationStringErrorKind::DuplicateKey("ä".to_string())
+            ))
+        );
+    }
+}
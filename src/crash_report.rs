@@ -0,0 +1,89 @@
+use std::any::Any;
+use std::backtrace::Backtrace;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use clap::crate_name;
+use serde::Serialize;
+
+use crate::run_spec::RunSpec;
+
+static CURRENT_RUN_SPEC: Mutex<Option<RunSpec>> = Mutex::new(None);
+
+#[derive(Serialize)]
+struct CrashReport {
+    application_version: String,
+    occurred_at: u64,
+    run_spec: RunSpec,
+    error: String,
+    location: Option<String>,
+    backtrace: String,
+}
+
+// Only runs which were started get a crash report. Errors caused by the configuration don't get
+// one either, see `ApplicationError::is_caused_by_configuration`.
+pub fn set_current_run_spec(run_spec: &RunSpec) {
+    if let Ok(mut current_run_spec) = CURRENT_RUN_SPEC.lock() {
+        *current_run_spec = Some(run_spec.clone());
+    }
+}
+
+pub fn clear_current_run_spec() {
+    if let Ok(mut current_run_spec) = CURRENT_RUN_SPEC.lock() {
+        *current_run_spec = None;
+    }
+}
+
+pub fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        default_hook(panic_info);
+        write(
+            panic_message(panic_info.payload()),
+            panic_info.location().map(|location| location.to_string()),
+        );
+    }));
+}
+
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+pub fn write(error: String, location: Option<String>) {
+    let run_spec = match CURRENT_RUN_SPEC.try_lock() {
+        Ok(mut current_run_spec) => match current_run_spec.take() {
+            Some(run_spec) => run_spec,
+            None => return,
+        },
+        Err(_) => return,
+    };
+    let occurred_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    let crash_report = CrashReport {
+        application_version: env!("CARGO_PKG_VERSION").to_string(),
+        occurred_at,
+        run_spec,
+        error,
+        location,
+        backtrace: Backtrace::force_capture().to_string(),
+    };
+
+    let path = format!("{}-crash-{}.json", crate_name!(), occurred_at);
+    match serde_json::to_string_pretty(&crash_report)
+        .map_err(|error| error.to_string())
+        .and_then(|content| std::fs::write(&path, content).map_err(|error| error.to_string()))
+    {
+        Ok(()) => eprintln!("A crash report was written to \"{}\".", path),
+        Err(error) => eprintln!(
+            "Could not write a crash report to \"{}\" ({}).",
+            path, error
+        ),
+    }
+}
@@ -0,0 +1,74 @@
+use std::path::Path;
+
+use clap::crate_name;
+
+use crate::error::ApplicationError;
+
+struct Check {
+    name: String,
+    result: Result<String, String>,
+}
+
+pub fn run_checks(directories: Vec<&str>) -> Result<(), ApplicationError> {
+    let mut checks = vec![
+        check_feature("piston", cfg!(feature = "piston")),
+        check_feature("gym-envs", cfg!(feature = "gym-envs")),
+        check_feature("code-bullet-envs", cfg!(feature = "code-bullet-envs")),
+        #[cfg(feature = "piston")]
+        check_display(),
+    ];
+    checks.extend(
+        directories
+            .into_iter()
+            .map(|directory| check_writable_directory(Path::new(directory))),
+    );
+
+    let failed_checks = checks.iter().filter(|check| check.result.is_err()).count();
+    for check in checks {
+        match check.result {
+            Ok(message) => println!("[PASS] {}: {}", check.name, message),
+            Err(message) => println!("[FAIL] {}: {}", check.name, message),
+        }
+    }
+
+    if failed_checks == 0 {
+        Ok(())
+    } else {
+        Err(ApplicationError::ChecksFailed(failed_checks))
+    }
+}
+
+// A feature which is not compiled in isn't a failure, the listed components are just missing.
+fn check_feature(feature: &str, enabled: bool) -> Check {
+    Check {
+        name: format!("feature \"{}\"", feature),
+        result: Ok(if enabled {
+            "compiled in".to_string()
+        } else {
+            "not compiled in".to_string()
+        }),
+    }
+}
+
+#[cfg(feature = "piston")]
+fn check_display() -> Check {
+    Check {
+        name: "display".to_string(),
+        result: if crate::display_available() {
+            Ok("available".to_string())
+        } else {
+            Err("not available because DISPLAY and WAYLAND_DISPLAY are not set".to_string())
+        },
+    }
+}
+
+fn check_writable_directory(directory: &Path) -> Check {
+    let probe_path = directory.join(format!(".{}-doctor-{}", crate_name!(), std::process::id()));
+    Check {
+        name: format!("directory \"{}\"", directory.display()),
+        result: std::fs::write(&probe_path, b"")
+            .and_then(|_| std::fs::remove_file(&probe_path))
+            .map(|_| "writable".to_string())
+            .map_err(|error| format!("not writable ({})", error)),
+    }
+}
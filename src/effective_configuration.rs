@@ -0,0 +1,392 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use clap::{Arg, ArgMatches};
+use serde_json::Value;
+
+use crate::error::ApplicationError;
+
+// Every argument of `command_line` which can also be given by a configuration file or an
+// environment variable. Later sources override earlier ones:
+// defaults < configuration file < environment variables < command line.
+const VALUE_ARGUMENTS: &[&str] = &[
+    "environment",
+    "environment_configuration",
+    "agent",
+    "agent_configuration",
+    "visualiser",
+    "visualiser_configuration",
+    "exit_condition",
+    "exit_condition_configuration",
+    "seed",
+    "environment_load_path",
+    "environment_store_path",
+    "agent_load_path",
+    "agent_store_path",
+    "manifest",
+    "on_complete",
+];
+
+const FLAG_ARGUMENTS: &[&str] = &[
+    "not_reset_environment_on_done",
+    "reset_agent_on_done",
+    "fall_back_to_no_visualiser",
+    "allow_unsupported_combination",
+    "smoke_test",
+];
+
+pub const ENVIRONMENT_VARIABLE_PREFIX: &str = "GYMNARIUM_";
+
+// Flags can be given as `--flag` or `--flag=false`, so the command line can also switch off a flag
+// which the configuration file or an environment variable switched on.
+pub fn flag<'a, 'b>(arg: Arg<'a, 'b>) -> Arg<'a, 'b> {
+    arg.takes_value(true)
+        .min_values(0)
+        .max_values(1)
+        .require_equals(true)
+        .possible_values(&["true", "false"])
+        .value_name("BOOL")
+}
+
+pub fn flag_value(matches: &ArgMatches, name: &str) -> bool {
+    matches.is_present(name) && matches.value_of(name) != Some("false")
+}
+
+/* -- -- -- -- -- -- -- -- -- -- -- -- -- --   SOURCE   -- -- -- -- -- -- -- -- -- -- -- -- -- -- */
+
+pub enum Source {
+    Default,
+    ConfigurationFile(String),
+    EnvironmentVariable(String),
+    CommandLine,
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Default => write!(f, "default"),
+            Self::ConfigurationFile(path) => write!(f, "configuration file \"{}\"", path),
+            Self::EnvironmentVariable(name) => write!(f, "environment variable {}", name),
+            Self::CommandLine => write!(f, "command line"),
+        }
+    }
+}
+
+/* -- -- -- -- -- -- -- -- -- -- -- -- - EFFECTIVE CONFIGURATION  -- -- -- -- -- -- -- -- -- -- -- */
+
+enum EffectiveValue {
+    Value(Option<String>),
+    Flag(bool),
+}
+
+pub struct EffectiveConfiguration {
+    values: BTreeMap<&'static str, (EffectiveValue, Source)>,
+}
+
+impl EffectiveConfiguration {
+    pub fn resolve(matches: &ArgMatches) -> Result<Self, ApplicationError> {
+        Self::resolve_with(matches, |variable| std::env::var(variable).ok())
+    }
+
+    fn resolve_with<F: Fn(&str) -> Option<String>>(
+        matches: &ArgMatches,
+        environment_variable: F,
+    ) -> Result<Self, ApplicationError> {
+        let mut values = BTreeMap::new();
+        for &name in VALUE_ARGUMENTS {
+            values.insert(
+                name,
+                (
+                    EffectiveValue::Value(matches.value_of(name).map(str::to_string)),
+                    Source::Default,
+                ),
+            );
+        }
+        for &name in FLAG_ARGUMENTS {
+            values.insert(name, (EffectiveValue::Flag(false), Source::Default));
+        }
+        let mut effective_configuration = Self { values };
+
+        if let Some(path) = matches.value_of("config") {
+            effective_configuration.apply_configuration_file(path)?;
+        }
+        effective_configuration.apply_environment_variables(environment_variable)?;
+        effective_configuration.apply_command_line(matches);
+
+        Ok(effective_configuration)
+    }
+
+    fn apply_configuration_file(&mut self, path: &str) -> Result<(), ApplicationError> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|error| ApplicationError::ConfigurationFileIo(path.to_string(), error))?;
+        let object = match serde_json::from_str(&content) {
+            Ok(Value::Object(object)) => object,
+            Ok(_) => {
+                return Err(ApplicationError::InvalidConfigurationFile(
+                    path.to_string(),
+                    "expected a JSON object".to_string(),
+                ))
+            }
+            Err(error) => {
+                return Err(ApplicationError::InvalidConfigurationFile(
+                    path.to_string(),
+                    error.to_string(),
+                ))
+            }
+        };
+
+        for (key, value) in object {
+            let (effective_value, source) = self
+                .values
+                .get_mut(key.replace('-', "_").as_str())
+                .ok_or_else(|| {
+                    ApplicationError::InvalidConfigurationFile(
+                        path.to_string(),
+                        format!("there is no argument called \"{}\"", key),
+                    )
+                })?;
+            *effective_value = match (&effective_value, value) {
+                (EffectiveValue::Value(_), Value::String(value)) => {
+                    EffectiveValue::Value(Some(value))
+                }
+                (EffectiveValue::Flag(_), Value::Bool(value)) => EffectiveValue::Flag(value),
+                (EffectiveValue::Value(_), _) => {
+                    return Err(ApplicationError::InvalidConfigurationFile(
+                        path.to_string(),
+                        format!("\"{}\" has to be a string", key),
+                    ))
+                }
+                (EffectiveValue::Flag(_), _) => {
+                    return Err(ApplicationError::InvalidConfigurationFile(
+                        path.to_string(),
+                        format!("\"{}\" has to be a boolean", key),
+                    ))
+                }
+            };
+            *source = Source::ConfigurationFile(path.to_string());
+        }
+        Ok(())
+    }
+
+    fn apply_environment_variables<F: Fn(&str) -> Option<String>>(
+        &mut self,
+        environment_variable: F,
+    ) -> Result<(), ApplicationError> {
+        for (name, (effective_value, source)) in self.values.iter_mut() {
+            let variable = format!("{}{}", ENVIRONMENT_VARIABLE_PREFIX, name.to_uppercase());
+            let value = match environment_variable(&variable) {
+                Some(value) => value,
+                None => continue,
+            };
+            *effective_value = match effective_value {
+                EffectiveValue::Value(_) => EffectiveValue::Value(Some(value)),
+                EffectiveValue::Flag(_) => match value.to_lowercase().as_str() {
+                    "1" | "true" | "yes" => EffectiveValue::Flag(true),
+                    "0" | "false" | "no" | "" => EffectiveValue::Flag(false),
+                    _ => {
+                        return Err(ApplicationError::InvalidEnvironmentVariable(
+                            variable, value,
+                        ))
+                    }
+                },
+            };
+            *source = Source::EnvironmentVariable(variable);
+        }
+        Ok(())
+    }
+
+    fn apply_command_line(&mut self, matches: &ArgMatches) {
+        for (name, (effective_value, source)) in self.values.iter_mut() {
+            if matches.occurrences_of(name) == 0 {
+                continue;
+            }
+            *effective_value = match effective_value {
+                EffectiveValue::Value(_) => {
+                    EffectiveValue::Value(matches.value_of(name).map(str::to_string))
+                }
+                EffectiveValue::Flag(_) => EffectiveValue::Flag(flag_value(matches, name)),
+            };
+            *source = Source::CommandLine;
+        }
+    }
+
+    pub fn value_of(&self, name: &str) -> Option<&str> {
+        match self.values.get(name) {
+            Some((EffectiveValue::Value(value), _)) => value.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn is_present(&self, name: &str) -> bool {
+        match self.values.get(name) {
+            Some((EffectiveValue::Flag(value), _)) => *value,
+            _ => false,
+        }
+    }
+
+    pub fn print(&self) {
+        for (name, (effective_value, source)) in &self.values {
+            match effective_value {
+                EffectiveValue::Value(Some(value)) => {
+                    println!("{} = \"{}\" ({})", name, value, source)
+                }
+                EffectiveValue::Value(None) => println!("{} is not set ({})", name, source),
+                EffectiveValue::Flag(value) => println!("{} = {} ({})", name, value, source),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use clap::App;
+
+    use super::*;
+
+    fn app<'a, 'b>() -> App<'a, 'b> {
+        App::new("test")
+            .arg(
+                Arg::with_name("environment")
+                    .long("environment")
+                    .takes_value(true),
+            )
+            .arg(Arg::with_name("seed").long("seed").takes_value(true))
+            .arg(flag(
+                Arg::with_name("reset_agent_on_done").long("reset-agent-on-done"),
+            ))
+            .arg(flag(Arg::with_name("smoke_test").long("smoke-test")))
+            .arg(Arg::with_name("config").long("config").takes_value(true))
+    }
+
+    fn resolve(
+        arguments: &[&str],
+        configuration_file: Option<&str>,
+        environment_variables: &[(&str, &str)],
+    ) -> EffectiveConfiguration {
+        static NEXT_FILE_NUMBER: AtomicUsize = AtomicUsize::new(0);
+
+        let mut arguments = arguments.to_vec();
+        let path = std::env::temp_dir().join(format!(
+            "gymnarium_application-test-{}-{}.json",
+            std::process::id(),
+            NEXT_FILE_NUMBER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let path = path.to_str().unwrap().to_string();
+        if let Some(content) = configuration_file {
+            std::fs::write(&path, content).unwrap();
+            arguments.push("--config");
+            arguments.push(&path);
+        }
+        let environment_variables = environment_variables
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect::<HashMap<String, String>>();
+
+        let matches = app().get_matches_from(std::iter::once("test").chain(arguments));
+        let effective_configuration = EffectiveConfiguration::resolve_with(&matches, |variable| {
+            environment_variables.get(variable).cloned()
+        });
+        if configuration_file.is_some() {
+            std::fs::remove_file(&path).unwrap();
+        }
+        effective_configuration.unwrap()
+    }
+
+    fn source_of(effective_configuration: &EffectiveConfiguration, name: &str) -> String {
+        effective_configuration.values[name].1.to_string()
+    }
+
+    #[test]
+    fn uses_defaults_without_any_source() {
+        let effective_configuration = resolve(&[], None, &[]);
+        assert_eq!(effective_configuration.value_of("seed"), None);
+        assert!(!effective_configuration.is_present("smoke_test"));
+        assert_eq!(source_of(&effective_configuration, "smoke_test"), "default");
+    }
+
+    #[test]
+    fn configuration_file_overrides_defaults() {
+        let effective_configuration =
+            resolve(&[], Some(r#"{"seed": "file", "smoke-test": true}"#), &[]);
+        assert_eq!(effective_configuration.value_of("seed"), Some("file"));
+        assert!(effective_configuration.is_present("smoke_test"));
+        assert!(source_of(&effective_configuration, "smoke_test").starts_with("configuration file"));
+    }
+
+    #[test]
+    fn environment_variables_override_configuration_file() {
+        let effective_configuration = resolve(
+            &[],
+            Some(r#"{"seed": "file", "smoke-test": true}"#),
+            &[
+                ("GYMNARIUM_SEED", "environment"),
+                ("GYMNARIUM_SMOKE_TEST", "false"),
+            ],
+        );
+        assert_eq!(
+            effective_configuration.value_of("seed"),
+            Some("environment")
+        );
+        assert!(!effective_configuration.is_present("smoke_test"));
+        assert_eq!(
+            source_of(&effective_configuration, "smoke_test"),
+            "environment variable GYMNARIUM_SMOKE_TEST"
+        );
+    }
+
+    #[test]
+    fn command_line_overrides_environment_variables() {
+        let effective_configuration = resolve(
+            &["--seed", "command line", "--reset-agent-on-done"],
+            None,
+            &[("GYMNARIUM_SEED", "environment")],
+        );
+        assert_eq!(
+            effective_configuration.value_of("seed"),
+            Some("command line")
+        );
+        assert!(effective_configuration.is_present("reset_agent_on_done"));
+        assert_eq!(source_of(&effective_configuration, "seed"), "command line");
+    }
+
+    #[test]
+    fn command_line_switches_off_flags() {
+        let effective_configuration = resolve(
+            &["--reset-agent-on-done=false", "--smoke-test=false"],
+            Some(r#"{"smoke-test": true}"#),
+            &[("GYMNARIUM_RESET_AGENT_ON_DONE", "1")],
+        );
+        assert!(!effective_configuration.is_present("reset_agent_on_done"));
+        assert!(!effective_configuration.is_present("smoke_test"));
+        assert_eq!(
+            source_of(&effective_configuration, "reset_agent_on_done"),
+            "command line"
+        );
+        assert_eq!(
+            source_of(&effective_configuration, "smoke_test"),
+            "command line"
+        );
+    }
+
+    #[test]
+    fn command_line_switches_on_flags_with_value() {
+        let effective_configuration = resolve(&["--smoke-test=true"], None, &[]);
+        assert!(effective_configuration.is_present("smoke_test"));
+    }
+
+    #[test]
+    fn rejects_invalid_environment_variable_flag() {
+        let matches = app().get_matches_from(vec!["test"]);
+        assert!(EffectiveConfiguration::resolve_with(&matches, |variable| {
+            if variable == "GYMNARIUM_SMOKE_TEST" {
+                Some("maybe".to_string())
+            } else {
+                None
+            }
+        })
+        .is_err());
+    }
+}
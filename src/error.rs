@@ -0,0 +1,158 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use crate::availables::SelectError;
+use crate::configuration_string::ConfigurationStringError;
+
+#[derive(Debug)]
+pub enum ApplicationError {
+    UnknownAvailable(String),
+    MissingArgument(&'static str),
+    ConfigurationFileIo(String, std::io::Error),
+    InvalidConfigurationFile(String, String),
+    InvalidEnvironmentVariable(String, String),
+    InvalidConfigurationString(&'static str, ConfigurationStringError),
+    Select(SelectError),
+    UnsupportedCombination(Vec<String>),
+    NoCodePath(String),
+    #[cfg(feature = "piston")]
+    NoDisplay,
+    #[cfg(feature = "piston")]
+    NoDisplayForAgent(&'static str),
+    #[cfg(feature = "piston")]
+    RunAborted,
+    NothingAvailable(String),
+    ChecksFailed(usize),
+    #[cfg(feature = "gui")]
+    Gui(String),
+    ManifestIo(String, std::io::Error),
+    ManifestFormat(String, serde_json::Error),
+    InvalidChoice(String),
+    Io(std::io::Error),
+}
+
+impl ApplicationError {
+    // Mistakes in the configuration are reported to the user and don't need a crash report.
+    pub fn is_caused_by_configuration(&self) -> bool {
+        match self {
+            Self::UnknownAvailable(_)
+            | Self::MissingArgument(_)
+            | Self::ConfigurationFileIo(_, _)
+            | Self::InvalidConfigurationFile(_, _)
+            | Self::InvalidEnvironmentVariable(_, _)
+            | Self::InvalidConfigurationString(_, _)
+            | Self::Select(_)
+            | Self::UnsupportedCombination(_)
+            | Self::NoCodePath(_)
+            | Self::NothingAvailable(_)
+            | Self::InvalidChoice(_) => true,
+            #[cfg(feature = "piston")]
+            Self::NoDisplay | Self::NoDisplayForAgent(_) | Self::RunAborted => true,
+            Self::ChecksFailed(_)
+            | Self::ManifestIo(_, _)
+            | Self::ManifestFormat(_, _)
+            | Self::Io(_) => false,
+            #[cfg(feature = "gui")]
+            Self::Gui(_) => false,
+        }
+    }
+}
+
+impl Error for ApplicationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::ConfigurationFileIo(_, error) => Some(error),
+            Self::InvalidConfigurationString(_, error) => Some(error),
+            Self::Select(error) => Some(error),
+            Self::Io(error) => Some(error),
+            Self::ManifestIo(_, error) => Some(error),
+            Self::ManifestFormat(_, error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ApplicationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownAvailable(error) => write!(f, "{}", error),
+            Self::MissingArgument(name) => write!(
+                f,
+                "--{} has to be given on the command line, in the configuration file or as \
+                environment variable",
+                name.replace('_', "-")
+            ),
+            Self::ConfigurationFileIo(path, _) => {
+                write!(f, "Could not read the configuration file \"{}\"", path)
+            }
+            Self::InvalidConfigurationFile(path, reason) => write!(
+                f,
+                "The configuration file \"{}\" is invalid: {}",
+                path, reason
+            ),
+            Self::InvalidEnvironmentVariable(variable, value) => write!(
+                f,
+                "The environment variable {} has to be true or false but is \"{}\"",
+                variable, value
+            ),
+            Self::InvalidConfigurationString(name, _) => {
+                write!(
+                    f,
+                    "Could not read the value of --{}",
+                    name.replace('_', "-")
+                )
+            }
+            Self::Select(_) => write!(f, "Could not apply the given configuration"),
+            Self::UnsupportedCombination(unsupported_combinations) => write!(
+                f,
+                "The selected combination is not supported: {}. Use \
+                --allow-unsupported-combination to proceed anyway.",
+                unsupported_combinations.join("; ")
+            ),
+            Self::NoCodePath(reason) => write!(
+                f,
+                "There is no code path for the selected combination: {}",
+                reason
+            ),
+            #[cfg(feature = "piston")]
+            Self::NoDisplay => write!(
+                f,
+                "There is no display to open the window on. Use --fall-back-to-no-visualiser to \
+                continue without a visualiser."
+            ),
+            #[cfg(feature = "piston")]
+            Self::NoDisplayForAgent(agent) => write!(
+                f,
+                "There is no display to open the window on. --fall-back-to-no-visualiser can't \
+                continue without a visualiser because the agent \"{}\" needs one.",
+                agent
+            ),
+            #[cfg(feature = "piston")]
+            Self::RunAborted => write!(f, "The run was aborted"),
+            Self::NothingAvailable(category) => {
+                write!(f, "There are no {} with the previous selections!", category)
+            }
+            Self::InvalidChoice(choice) => write!(f, "Couldn't parse \"{}\"", choice),
+            Self::Io(_) => write!(f, "Could not communicate over the terminal"),
+            #[cfg(feature = "gui")]
+            Self::Gui(error) => write!(f, "Could not open the window ({})", error),
+            Self::ChecksFailed(count) => write!(f, "{} of the checks failed", count),
+            Self::ManifestIo(path, _) => write!(f, "Could not access the manifest \"{}\"", path),
+            Self::ManifestFormat(path, _) => {
+                write!(f, "Could not convert the manifest \"{}\"", path)
+            }
+        }
+    }
+}
+
+impl From<SelectError> for ApplicationError {
+    fn from(error: SelectError) -> Self {
+        ApplicationError::Select(error)
+    }
+}
+
+impl From<std::io::Error> for ApplicationError {
+    fn from(error: std::io::Error) -> Self {
+        ApplicationError::Io(error)
+    }
+}
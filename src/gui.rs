@@ -0,0 +1,235 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::error::Error;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use eframe::egui;
+
+use crate::availables::*;
+use crate::error::ApplicationError;
+use crate::run_spec::RunSpec;
+use crate::APP_NAME;
+
+/* -- -- -- -- -- -- -- -- -- -- -- -- -- -  CATEGORY FORM  - -- -- -- -- -- -- -- -- -- -- -- -- -- */
+
+struct CategoryForm<S: Selected<A>, A: Available<S>> {
+    values: Vec<A>,
+    selected: usize,
+    configuration: Vec<(AvailableConfiguration, String)>,
+    _selected: PhantomData<S>,
+}
+
+impl<S: Selected<A>, A: Available<S> + Clone> CategoryForm<S, A> {
+    fn new() -> Self {
+        let mut category_form = Self {
+            values: A::values(),
+            selected: 0,
+            configuration: Vec::new(),
+            _selected: PhantomData,
+        };
+        category_form.reset_configuration();
+        category_form
+    }
+
+    fn reset_configuration(&mut self) {
+        self.configuration = self.values[self.selected]
+            .available_configurations()
+            .into_iter()
+            .map(|available_configuration| {
+                let default = available_configuration.default.clone();
+                (available_configuration, default)
+            })
+            .collect();
+    }
+
+    fn show(&mut self, ui: &mut egui::Ui) {
+        ui.heading(A::category_headline());
+        let previously_selected = self.selected;
+        egui::ComboBox::from_id_salt(A::category_headline())
+            .selected_text(self.values[self.selected].nice_name())
+            .show_ui(ui, |ui| {
+                for (index, available) in self.values.iter().enumerate() {
+                    ui.selectable_value(&mut self.selected, index, available.nice_name());
+                }
+            });
+        if self.selected != previously_selected {
+            self.reset_configuration();
+        }
+
+        for (available_configuration, value) in &mut self.configuration {
+            ui.horizontal(|ui| {
+                ui.label(format!(
+                    "{} [{}]",
+                    available_configuration.name, available_configuration.data_type
+                ))
+                .on_hover_text(&available_configuration.description);
+                ui.text_edit_singleline(value);
+            });
+            if let Err(reason) = available_configuration.data_type.parse(value) {
+                ui.colored_label(egui::Color32::RED, reason);
+            }
+        }
+        ui.separator();
+    }
+
+    fn select(&self) -> Result<S, ApplicationError> {
+        let configuration = self
+            .configuration
+            .iter()
+            .map(|(available_configuration, value)| {
+                (available_configuration.name.clone(), value.clone())
+            })
+            .collect::<HashMap<String, String>>();
+        Ok(self.values[self.selected].clone().select(configuration)?)
+    }
+}
+
+/* -- -- -- -- -- -- -- -- -- -- -- -- -- GUI APPLICATION  -- -- -- -- -- -- -- -- -- -- -- -- -- */
+
+struct GuiApplication {
+    environment: CategoryForm<SelectedEnvironment, AvailableEnvironment>,
+    agent: CategoryForm<SelectedAgent, AvailableAgent>,
+    visualiser: CategoryForm<SelectedVisualiser, AvailableVisualiser>,
+    exit_condition: CategoryForm<SelectedExitCondition, AvailableExitCondition>,
+    seed: String,
+    reset_environment_on_done: bool,
+    reset_agent_on_done: bool,
+    environment_load_path: String,
+    environment_store_path: String,
+    agent_load_path: String,
+    agent_store_path: String,
+    allow_unsupported_combination: bool,
+    error: Option<String>,
+    run_spec: Rc<RefCell<Option<RunSpec>>>,
+}
+
+impl GuiApplication {
+    fn run_spec(&self) -> Result<RunSpec, ApplicationError> {
+        fn non_empty(string: &str) -> Option<String> {
+            if string.trim().is_empty() {
+                None
+            } else {
+                Some(string.trim().to_string())
+            }
+        }
+
+        let run_spec = RunSpec {
+            environment: self.environment.select()?,
+            agent: self.agent.select()?,
+            visualiser: self.visualiser.select()?,
+            exit_condition: self.exit_condition.select()?,
+            seed: non_empty(&self.seed),
+            reset_environment_on_done: self.reset_environment_on_done,
+            reset_agent_on_done: self.reset_agent_on_done,
+            environment_load_path: non_empty(&self.environment_load_path),
+            environment_store_path: non_empty(&self.environment_store_path),
+            agent_load_path: non_empty(&self.agent_load_path),
+            agent_store_path: non_empty(&self.agent_store_path),
+            fall_back_to_no_visualiser: false,
+            allow_unsupported_combination: self.allow_unsupported_combination,
+        };
+        crate::check_combination(
+            &run_spec.environment,
+            &run_spec.agent,
+            &run_spec.visualiser,
+            &run_spec.exit_condition,
+            run_spec.allow_unsupported_combination,
+        )?;
+        Ok(run_spec)
+    }
+}
+
+impl eframe::App for GuiApplication {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                self.environment.show(ui);
+                self.agent.show(ui);
+                self.visualiser.show(ui);
+                self.exit_condition.show(ui);
+
+                ui.heading("Run Options");
+                ui.horizontal(|ui| {
+                    ui.label("Seed");
+                    ui.text_edit_singleline(&mut self.seed)
+                        .on_hover_text("Leave empty to choose the seed randomly.");
+                });
+                ui.checkbox(
+                    &mut self.reset_environment_on_done,
+                    "Reset the environment when it is done",
+                );
+                ui.checkbox(
+                    &mut self.reset_agent_on_done,
+                    "Reset the agent when the environment is done",
+                );
+                for (label, path) in [
+                    ("Load environment from", &mut self.environment_load_path),
+                    ("Store environment to", &mut self.environment_store_path),
+                    ("Load agent from", &mut self.agent_load_path),
+                    ("Store agent to", &mut self.agent_store_path),
+                ] {
+                    ui.horizontal(|ui| {
+                        ui.label(label);
+                        ui.text_edit_singleline(path);
+                    });
+                }
+                ui.checkbox(
+                    &mut self.allow_unsupported_combination,
+                    "Allow unsupported combinations",
+                );
+                ui.separator();
+
+                if ui.button("Run").clicked() {
+                    match self.run_spec() {
+                        Ok(run_spec) => {
+                            *self.run_spec.borrow_mut() = Some(run_spec);
+                            ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+                        }
+                        Err(error) => {
+                            let mut message = error.to_string();
+                            let mut source = error.source();
+                            while let Some(error) = source {
+                                message.push_str(&format!("\n{}", error));
+                                source = error.source();
+                            }
+                            self.error = Some(message);
+                        }
+                    }
+                }
+                if let Some(error) = &self.error {
+                    ui.colored_label(egui::Color32::RED, error);
+                }
+            });
+        });
+    }
+}
+
+// The run itself starts after the window is closed, so the visualiser doesn't compete with it.
+pub fn select_run_spec() -> Result<Option<RunSpec>, ApplicationError> {
+    let run_spec = Rc::new(RefCell::new(None));
+    let gui_application = GuiApplication {
+        environment: CategoryForm::new(),
+        agent: CategoryForm::new(),
+        visualiser: CategoryForm::new(),
+        exit_condition: CategoryForm::new(),
+        seed: String::new(),
+        reset_environment_on_done: true,
+        reset_agent_on_done: false,
+        environment_load_path: String::new(),
+        environment_store_path: String::new(),
+        agent_load_path: String::new(),
+        agent_store_path: String::new(),
+        allow_unsupported_combination: false,
+        error: None,
+        run_spec: run_spec.clone(),
+    };
+    eframe::run_native(
+        APP_NAME,
+        eframe::NativeOptions::default(),
+        Box::new(|_| Ok(Box::new(gui_application))),
+    )
+    .map_err(|error| ApplicationError::Gui(error.to_string()))?;
+    let run_spec = run_spec.borrow_mut().take();
+    Ok(run_spec)
+}
@@ -2,29 +2,64 @@ extern crate clap;
 extern crate gymnarium;
 
 mod availables;
+mod completion_hook;
+mod configuration;
+mod configuration_string;
+mod crash_report;
+mod doctor;
+mod effective_configuration;
+mod error;
+#[cfg(feature = "gui")]
+mod gui;
+mod manifest;
+mod quickstart;
+mod resource_usage;
+mod run_spec;
 
 use std::collections::HashMap;
 use std::error::Error;
 use std::io::Write;
+use std::panic::AssertUnwindSafe;
 use std::str::FromStr;
+use std::time::Instant;
 
 use clap::{
-    crate_authors, crate_description, crate_version, App, AppSettings, Arg, ArgMatches, SubCommand,
+    crate_authors, crate_description, crate_name, crate_version, App, AppSettings, Arg, ArgMatches,
+    SubCommand,
 };
 
 use gymnarium::gymnarium_agents_random::RandomAgent;
-use gymnarium::gymnarium_base::{ActionSpace, Environment, Reward, Seed, ToActionMapper};
-use gymnarium::gymnarium_environments_gym::mountain_car::{
-    MountainCar, MountainCarInputToActionMapper,
-};
-use gymnarium::gymnarium_environments_tiquthon::code_bullet::ai_learns_to_drive::{
-    AiLearnsToDrive, AiLearnsToDriveInputToActionMapper,
-};
+#[cfg(feature = "piston")]
+use gymnarium::gymnarium_base::ToActionMapper;
+use gymnarium::gymnarium_base::{ActionSpace, Environment, Reward};
+#[cfg(feature = "gym-envs")]
+use gymnarium::gymnarium_environments_gym::mountain_car::MountainCar;
+#[cfg(all(feature = "gym-envs", feature = "piston"))]
+use gymnarium::gymnarium_environments_gym::mountain_car::MountainCarInputToActionMapper;
+#[cfg(feature = "code-bullet-envs")]
+use gymnarium::gymnarium_environments_tiquthon::code_bullet::ai_learns_to_drive::AiLearnsToDrive;
+#[cfg(all(feature = "code-bullet-envs", feature = "piston"))]
+use gymnarium::gymnarium_environments_tiquthon::code_bullet::ai_learns_to_drive::AiLearnsToDriveInputToActionMapper;
+#[cfg(feature = "piston")]
 use gymnarium::gymnarium_visualisers_base::{input, InputAgent, InputProvider};
+#[cfg(feature = "piston")]
 use gymnarium::gymnarium_visualisers_piston::PistonVisualiser;
-use gymnarium::{run_with_no_visualiser, run_with_two_dimensional_visualiser, RunOptions};
+use gymnarium::run_with_no_visualiser;
+#[cfg(feature = "piston")]
+use gymnarium::run_with_two_dimensional_visualiser;
 
 use crate::availables::*;
+use crate::configuration::json_schema_of;
+use crate::effective_configuration::{
+    flag, flag_value, EffectiveConfiguration, ENVIRONMENT_VARIABLE_PREFIX,
+};
+use crate::error::ApplicationError;
+use crate::manifest::Manifest;
+use crate::resource_usage::ResourceUsage;
+use crate::run_spec::RunSpec;
+
+#[cfg(not(any(feature = "gym-envs", feature = "code-bullet-envs")))]
+compile_error!("At least one of the features \"gym-envs\" and \"code-bullet-envs\" is required.");
 
 const APP_NAME: &str = "Gymnarium Application";
 
@@ -63,6 +98,8 @@ fn main() {
         )
     }
 
+    crash_report::install_panic_hook();
+
     let matches = App::new(APP_NAME)
         .version(crate_version!())
         .author(crate_authors!(", "))
@@ -70,8 +107,27 @@ fn main() {
         .long_about("")
         .setting(AppSettings::SubcommandRequiredElseHelp)
         .setting(AppSettings::VersionlessSubcommands)
+        .subcommands(optional_subcommands())
+        .subcommand(SubCommand::with_name("quickstart")
+            .about("offers a few ready-made runs to get started with"))
+        .subcommand(SubCommand::with_name("schema")
+            .about("prints a JSON schema of all configuration options"))
+        .subcommand(SubCommand::with_name("doctor")
+            .about("checks whether this machine can run the compiled in components")
+            .arg(Arg::with_name("directory")
+                .help("directories which should be writable, e.g. for stored agents")
+                .multiple(true)
+                .default_value(".")
+                .value_name("DIRECTORY")))
+        .subcommand(SubCommand::with_name("from_manifest")
+            .about("replays the run described by a manifest")
+            .arg(Arg::with_name("manifest")
+                .help("the manifest written by `command_line --manifest`")
+                .required(true)
+                .value_name("PATH")))
         .subcommand(SubCommand::with_name("interactive")
-            .about("asks every configurable option interactively"))
+            .about("asks every configurable option interactively")
+            .arg(allow_unsupported_combination_arg()))
         .subcommand(SubCommand::with_name("command_line")
             .about("only accepts command line arguments; see `command_line --help` for help")
             .arg(Arg::with_name("environment")
@@ -89,7 +145,6 @@ fn main() {
                         .map(format_available_value)
                         .fold(String::new(), |result, line| result + &line)
                 ))
-                .required(true)
                 .takes_value(true)
                 .hide_possible_values(true)
                 .possible_values(
@@ -284,20 +339,20 @@ fn main() {
                 .takes_value(true)
                 .value_name("SEED")
                 .display_order(50))
-            .arg(Arg::with_name("not_reset_environment_on_done")
+            .arg(flag(Arg::with_name("not_reset_environment_on_done")
                 .short("r")
                 .long("not-reset-environment-on-done")
                 .help("does not reset the environment when the environment says it's done")
                 .long_help("After every step the environment returns if the current episode is \
                 done. With this flag the given environment does not get reset if this happens.")
-                .display_order(60))
-            .arg(Arg::with_name("reset_agent_on_done")
+                .display_order(60)))
+            .arg(flag(Arg::with_name("reset_agent_on_done")
                 .short("q")
                 .long("reset-agent-on-done")
                 .help("resets the agent when the environment says it's done")
                 .long_help("After every step the environment returns if the current episode is \
                 done. With this flag the given agent gets reset if this happens.")
-                .display_order(70))
+                .display_order(70)))
             .arg(Arg::with_name("environment_load_path")
                 .short("j")
                 .long("environment-load-path")
@@ -345,114 +400,222 @@ fn main() {
                 encoding scheme).")
                 .takes_value(true)
                 .value_name("PATH")
-                .display_order(110)))
+                .display_order(110))
+            .arg(flag(Arg::with_name("fall_back_to_no_visualiser")
+                .long("fall-back-to-no-visualiser")
+                .help("continues without a visualiser if there is no display")
+                .long_help("Continues with the visualiser None instead of failing if a visualiser \
+                which opens a window was selected but there is no display to open it on. The exit \
+                condition visualiser is closed is then replaced by episodes done simulating with \
+                its default count. The input agent still fails, because it can't work without a \
+                window. This allows the same configuration to be used on workstations and \
+                headless servers.")
+                .display_order(120)))
+            .arg(Arg::with_name("manifest")
+                .long("manifest")
+                .help("writes a manifest to replay this run with")
+                .long_help("Writes a manifest containing the resolved configuration, the seed, the \
+                application version and timestamps to the given file. If no seed was given one is \
+                chosen and recorded. The run can be repeated with `from_manifest PATH`. The file is \
+                written as JSON and will be overwritten.")
+                .takes_value(true)
+                .value_name("PATH")
+                .display_order(130))
+            .arg(Arg::with_name("on_complete")
+                .long("on-complete")
+                .help("runs this command when the run ends")
+                .long_help("Runs the given command with the system shell when the run ends, no \
+                matter if it succeeded, failed or panicked. The command receives a JSON summary of the run \
+                on its standard input and the environment variable GYMNARIUM_RUN_STATUS is set to \
+                \"succeeded\" or \"failed\".")
+                .takes_value(true)
+                .value_name("COMMAND")
+                .display_order(140))
+            .arg(flag(Arg::with_name("smoke_test")
+                .long("smoke-test")
+                .help("only checks that the selected combination runs")
+                .long_help("Replaces the exit condition with a single simulated episode, removes \
+                the frame limit of the visualiser and does not store the environment or the agent. \
+                This verifies that the selected combination executes end-to-end, e.g. for \
+                checking configurations in CI.")
+                .display_order(150)))
+            .arg(Arg::with_name("config")
+                .long("config")
+                .help("reads the arguments from this JSON file")
+                .long_help(&format!("Reads the arguments from the given JSON file. It contains an \
+                object with the long names of the arguments as keys, e.g. {{\"environment\": \
+                \"gym_mountaincar\", \"reset-agent-on-done\": true}}. Every argument can also be \
+                given as an environment variable like {}ENVIRONMENT or {}RESET_AGENT_ON_DONE. \
+                Environment variables override the file and the command line overrides both. Flags \
+                can be switched off on the command line with e.g. --reset-agent-on-done=false.",
+                    ENVIRONMENT_VARIABLE_PREFIX, ENVIRONMENT_VARIABLE_PREFIX))
+                .takes_value(true)
+                .value_name("PATH")
+                .display_order(5))
+            .arg(Arg::with_name("print_effective_config")
+                .long("print-effective-config")
+                .help("prints the merged arguments and where they came from instead of running")
+                .display_order(210))
+            .arg(allow_unsupported_combination_arg()))
         .get_matches();
 
-    if let Some(matched_subcommand_args) = matches.subcommand_matches("command_line") {
-        start_with_config(matched_subcommand_args);
-    } else if matches.subcommand_matches("interactive").is_some() {
-        start_interactively();
+    let result = match matches.subcommand() {
+        ("command_line", Some(matched_subcommand_args)) => {
+            start_with_config(matched_subcommand_args)
+        }
+        ("doctor", Some(matched_subcommand_args)) => doctor::run_checks(
+            matched_subcommand_args
+                .values_of("directory")
+                .unwrap()
+                .collect(),
+        ),
+        ("from_manifest", Some(matched_subcommand_args)) => {
+            start_from_manifest(matched_subcommand_args.value_of("manifest").unwrap())
+        }
+        #[cfg(feature = "gui")]
+        ("gui", Some(_)) => start_gui(),
+        ("quickstart", Some(_)) => start_quickstart(),
+        ("schema", Some(_)) => print_configuration_schema(),
+        ("interactive", Some(matched_subcommand_args)) => start_interactively(flag_value(
+            matched_subcommand_args,
+            "allow_unsupported_combination",
+        )),
+        _ => Ok(()),
+    };
+
+    if let Err(error) = result {
+        let mut message = format!("Error: {}", error);
+        let mut source = error.source();
+        while let Some(error) = source {
+            message.push_str(&format!("\nCaused by: {}", error));
+            source = error.source();
+        }
+        eprintln!("{}", message);
+        if !error.is_caused_by_configuration() {
+            crash_report::write(message, None);
+        }
+        std::process::exit(1);
     }
 }
 
-fn start_with_config(matched_subcommand_args: &ArgMatches) {
-    fn split_config(configuration_string: &str) -> HashMap<String, String> {
-        let mut output = HashMap::default();
-        let mut key = String::new();
-        let mut value = String::new();
-        let mut currently_parsing_value = false;
-        let mut next_escaped = false;
-        for c in configuration_string.chars() {
-            if !next_escaped && c == '\\' {
-                next_escaped = true;
-            } else if !next_escaped && !currently_parsing_value && c == '=' {
-                currently_parsing_value = true;
-            } else if !next_escaped && currently_parsing_value && c == ';' {
-                output.insert(key, value);
-                key = String::new();
-                value = String::new();
-                currently_parsing_value = false;
-            } else {
-                next_escaped = false;
-                if currently_parsing_value {
-                    value.push(c);
-                } else {
-                    key.push(c);
-                }
-            }
-        }
-        if currently_parsing_value {
-            output.insert(key, value);
+fn optional_subcommands<'a, 'b>() -> Vec<App<'a, 'b>> {
+    vec![
+        #[cfg(feature = "gui")]
+        SubCommand::with_name("gui").about("selects everything in a window and starts the run"),
+    ]
+}
+
+fn allow_unsupported_combination_arg<'a, 'b>() -> Arg<'a, 'b> {
+    flag(Arg::with_name("allow_unsupported_combination"))
+        .long("allow-unsupported-combination")
+        .help("proceeds even if the selected components are not marked as compatible")
+        .long_help(
+            "Every environment, agent, visualiser and exit condition declares which other \
+            components it supports. Without this flag a combination which is not supported is \
+            rejected. With this flag only a warning is printed and the run proceeds as far as \
+            there is a code path for the selected combination.",
+        )
+        .display_order(200)
+}
+
+fn print_configuration_schema() -> Result<(), ApplicationError> {
+    let schema = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": format!("{} configuration options", APP_NAME),
+        "definitions": {
+            "environments": json_schema_of::<_, AvailableEnvironment>(),
+            "agents": json_schema_of::<_, AvailableAgent>(),
+            "visualisers": json_schema_of::<_, AvailableVisualiser>(),
+            "exit_conditions": json_schema_of::<_, AvailableExitCondition>()
         }
-        output
+    });
+    println!("{:#}", schema);
+    Ok(())
+}
+
+fn start_with_config(matched_subcommand_args: &ArgMatches) -> Result<(), ApplicationError> {
+    fn parse_configuration(
+        effective_configuration: &EffectiveConfiguration,
+        name: &'static str,
+    ) -> Result<HashMap<String, String>, ApplicationError> {
+        configuration_string::parse(effective_configuration.value_of(name).unwrap())
+            .map_err(|error| ApplicationError::InvalidConfigurationString(name, error))
     }
 
-    let selected_environment = matched_subcommand_args
+    let effective_configuration = EffectiveConfiguration::resolve(matched_subcommand_args)?;
+    if matched_subcommand_args.is_present("print_effective_config") {
+        effective_configuration.print();
+        return Ok(());
+    }
+
+    let selected_environment = effective_configuration
         .value_of("environment")
-        .unwrap()
+        .ok_or(ApplicationError::MissingArgument("environment"))?
         .parse::<AvailableEnvironment>()
-        .unwrap()
-        .select(split_config(
-            matched_subcommand_args
-                .value_of("environment_configuration")
-                .unwrap(),
-        ))
-        .unwrap();
+        .map_err(ApplicationError::UnknownAvailable)?
+        .select(parse_configuration(
+            &effective_configuration,
+            "environment_configuration",
+        )?)?;
 
-    let selected_agent = matched_subcommand_args
+    let selected_agent = effective_configuration
         .value_of("agent")
         .unwrap()
         .parse::<AvailableAgent>()
-        .unwrap()
-        .select(split_config(
-            matched_subcommand_args
-                .value_of("agent_configuration")
-                .unwrap(),
-        ))
-        .unwrap();
+        .map_err(ApplicationError::UnknownAvailable)?
+        .select(parse_configuration(
+            &effective_configuration,
+            "agent_configuration",
+        )?)?;
 
-    let selected_visualiser = matched_subcommand_args
+    let selected_visualiser = effective_configuration
         .value_of("visualiser")
         .unwrap()
         .parse::<AvailableVisualiser>()
-        .unwrap()
-        .select(split_config(
-            matched_subcommand_args
-                .value_of("visualiser_configuration")
-                .unwrap(),
-        ))
-        .unwrap();
+        .map_err(ApplicationError::UnknownAvailable)?
+        .select(parse_configuration(
+            &effective_configuration,
+            "visualiser_configuration",
+        )?)?;
 
-    let selected_exit_condition = matched_subcommand_args
+    let selected_exit_condition = effective_configuration
         .value_of("exit_condition")
         .unwrap()
         .parse::<AvailableExitCondition>()
-        .unwrap()
-        .select(split_config(
-            matched_subcommand_args
-                .value_of("exit_condition_configuration")
-                .unwrap(),
-        ))
-        .unwrap();
+        .map_err(ApplicationError::UnknownAvailable)?
+        .select(parse_configuration(
+            &effective_configuration,
+            "exit_condition_configuration",
+        )?)?;
 
-    let seed: Option<Seed> = matched_subcommand_args.value_of("seed").map(Seed::from);
+    let seed: Option<String> = effective_configuration
+        .value_of("seed")
+        .map(|string| string.to_string());
     let reset_environment_on_done: bool =
-        !matched_subcommand_args.is_present("not_reset_environment_on_done");
-    let reset_agent_on_done: bool = matched_subcommand_args.is_present("reset_agent_on_done");
-    let environment_load_path: Option<String> = matched_subcommand_args
+        !effective_configuration.is_present("not_reset_environment_on_done");
+    let reset_agent_on_done: bool = effective_configuration.is_present("reset_agent_on_done");
+    let environment_load_path: Option<String> = effective_configuration
         .value_of("environment_load_path")
         .map(|string| string.to_string());
-    let environment_store_path: Option<String> = matched_subcommand_args
+    let environment_store_path: Option<String> = effective_configuration
         .value_of("environment_store_path")
         .map(|string| string.to_string());
-    let agent_load_path: Option<String> = matched_subcommand_args
+    let agent_load_path: Option<String> = effective_configuration
         .value_of("agent_load_path")
         .map(|string| string.to_string());
-    let agent_store_path: Option<String> = matched_subcommand_args
+    let agent_store_path: Option<String> = effective_configuration
         .value_of("agent_store_path")
         .map(|string| string.to_string());
+    let fall_back_to_no_visualiser: bool =
+        effective_configuration.is_present("fall_back_to_no_visualiser");
+    let allow_unsupported_combination: bool =
+        effective_configuration.is_present("allow_unsupported_combination");
 
-    let run_options = RunOptions {
+    let run_spec = RunSpec {
+        environment: selected_environment,
+        agent: selected_agent,
+        visualiser: selected_visualiser,
+        exit_condition: selected_exit_condition,
         seed,
         reset_environment_on_done,
         reset_agent_on_done,
@@ -460,18 +623,138 @@ fn start_with_config(matched_subcommand_args: &ArgMatches) {
         environment_store_path,
         agent_load_path,
         agent_store_path,
+        fall_back_to_no_visualiser,
+        allow_unsupported_combination,
     };
 
-    start(
-        selected_environment,
-        selected_agent,
-        selected_visualiser,
-        selected_exit_condition,
-        run_options,
+    let run_spec = if effective_configuration.is_present("smoke_test") {
+        run_spec.into_smoke_test()
+    } else {
+        run_spec
+    };
+
+    // The seed is chosen before anything copies the run spec, so the manifest, the summary of
+    // --on-complete and the run itself all use the same one.
+    let manifest_path = effective_configuration.value_of("manifest");
+    let run_spec = if manifest_path.is_some() {
+        run_spec.with_chosen_seed()
+    } else {
+        run_spec
+    };
+
+    let on_complete = effective_configuration
+        .value_of("on_complete")
+        .map(|command| (command, run_spec.clone(), Instant::now()));
+
+    let start_run = move || match manifest_path {
+        Some(manifest_path) => start_with_manifest(run_spec, manifest_path),
+        None => start(run_spec),
+    };
+
+    match on_complete {
+        // gymnarium panics on failures inside a run, so the command has to run for those too.
+        Some((command, run_spec, started_at)) => {
+            match std::panic::catch_unwind(AssertUnwindSafe(start_run)) {
+                Ok(result) => {
+                    completion_hook::run(
+                        command,
+                        &run_spec,
+                        result.as_ref().err().map(|error| error.to_string()),
+                        started_at.elapsed(),
+                    );
+                    result
+                }
+                Err(payload) => {
+                    completion_hook::run(
+                        command,
+                        &run_spec,
+                        Some(crash_report::panic_message(payload.as_ref())),
+                        started_at.elapsed(),
+                    );
+                    std::panic::resume_unwind(payload)
+                }
+            }
+        }
+        None => start_run(),
+    }
+}
+
+fn start_with_manifest(run_spec: RunSpec, manifest_path: &str) -> Result<(), ApplicationError> {
+    let mut manifest = Manifest::new(run_spec);
+    manifest.store(manifest_path)?;
+    start(manifest.run_spec.clone())?;
+    manifest.finish();
+    manifest.store(manifest_path)
+}
+
+fn start_from_manifest(manifest_path: &str) -> Result<(), ApplicationError> {
+    let manifest = Manifest::load(manifest_path)?;
+    if manifest.application_version != crate_version!() {
+        eprintln!(
+            "Warning: The manifest was written by version {} but this is version {}.",
+            manifest.application_version,
+            crate_version!()
+        );
+    }
+    start(manifest.run_spec)
+}
+
+#[cfg(feature = "gui")]
+fn start_gui() -> Result<(), ApplicationError> {
+    match gui::select_run_spec()? {
+        Some(run_spec) => start(run_spec),
+        None => Ok(()),
+    }
+}
+
+fn start_quickstart() -> Result<(), ApplicationError> {
+    println!(
+        "{} {}\n\nWhat would you like to start with?",
+        APP_NAME,
+        crate_version!()
     );
+
+    // Presets which open a window are left out if there is no display to open it on.
+    let (presets, hidden_presets): (Vec<_>, Vec<_>) = quickstart::presets()
+        .into_iter()
+        .partition(|preset| preset.can_open_visualiser());
+    println!();
+    for (index, preset) in presets.iter().enumerate() {
+        println!("<{}> {}", index, preset.description);
+    }
+    if !hidden_presets.is_empty() {
+        println!(
+            "({} presets opening a window are hidden because there is no display.)",
+            hidden_presets.len()
+        );
+    }
+    print!("Your choice: ");
+    std::io::stdout().flush()?;
+
+    let mut chosen_preset_string = String::new();
+    std::io::stdin().read_line(&mut chosen_preset_string)?;
+    let run_spec = usize::from_str(chosen_preset_string.trim())
+        .ok()
+        .and_then(|index| presets.get(index))
+        .ok_or_else(|| ApplicationError::InvalidChoice(chosen_preset_string.trim().to_string()))?
+        .run_spec()?;
+
+    println!();
+    println!(
+        "The same run can be started with:\n{}",
+        equivalent_command_line(
+            &run_spec,
+            &HashMap::new(),
+            &HashMap::new(),
+            &HashMap::new(),
+            &HashMap::new(),
+        )
+    );
+
+    start(run_spec)
 }
 
-fn start_interactively() {
+fn start_interactively(allow_unsupported_combination: bool) -> Result<(), ApplicationError> {
     println!(
         "{} {}\n\nIn the following steps the necessary configuration values will be collected.",
         APP_NAME,
@@ -479,7 +762,8 @@ fn start_interactively() {
     );
 
     // ENVIRONMENT
-    let selected_environment = select_interactively::<_, AvailableEnvironment, _>(|_| true);
+    let (selected_environment, environment_configuration) =
+        select_interactively::<_, AvailableEnvironment, _>(|_| true)?;
     let selected_environment_supports_visualiser = selected_environment
         .corresponding_available()
         .supports_available();
@@ -491,9 +775,26 @@ fn start_interactively() {
         .supports_available();
 
     // VISUALISER
-    let selected_visualiser = select_interactively::<_, AvailableVisualiser, _>(|available| {
-        selected_environment_supports_visualiser.contains(available)
-    });
+    let (selected_visualiser, visualiser_configuration) =
+        select_interactively::<_, AvailableVisualiser, _>(|available| {
+            allow_unsupported_combination
+                || selected_environment_supports_visualiser.contains(available)
+        })?;
+    #[cfg(feature = "piston")]
+    let (selected_visualiser, visualiser_configuration) = match selected_visualiser {
+        SelectedVisualiser::PistonIn2d { .. } if !display_available() => {
+            if prompt_yes_no(
+                "There is no display to open the window on. Should the run continue without a \
+                visualiser?",
+                true,
+            )? {
+                (SelectedVisualiser::None, HashMap::new())
+            } else {
+                return Err(ApplicationError::RunAborted);
+            }
+        }
+        selected_visualiser => (selected_visualiser, visualiser_configuration),
+    };
     let selected_visualiser_supports_agent = selected_visualiser
         .corresponding_available()
         .supports_available();
@@ -502,62 +803,68 @@ fn start_interactively() {
         .supports_available();
 
     // AGENT
-    let selected_agent = select_interactively::<_, AvailableAgent, _>(|available| {
-        selected_environment_supports_agent.contains(available)
-            && selected_visualiser_supports_agent.contains(available)
-    });
+    let (selected_agent, agent_configuration) =
+        select_interactively::<_, AvailableAgent, _>(|available| {
+            allow_unsupported_combination
+                || (selected_environment_supports_agent.contains(available)
+                    && selected_visualiser_supports_agent.contains(available))
+        })?;
     let selected_agent_supports_exit_condition = selected_agent
         .corresponding_available()
         .supports_available();
 
     // EXIT CONDITION
-    let selected_exit_condition =
+    let (selected_exit_condition, exit_condition_configuration) =
         select_interactively::<_, AvailableExitCondition, _>(|available| {
-            selected_environment_supports_exit_condition.contains(available)
-                && selected_visualiser_supports_exit_condition.contains(available)
-                && selected_agent_supports_exit_condition.contains(available)
-        });
+            allow_unsupported_combination
+                || (selected_environment_supports_exit_condition.contains(available)
+                    && selected_visualiser_supports_exit_condition.contains(available)
+                    && selected_agent_supports_exit_condition.contains(available))
+        })?;
 
     // RESET ON DONE
     let reset_environment_on_done = prompt_yes_no(
         "Should the ENVIRONMENT be resetted, when the environment is done after a step?",
         true,
-    );
+    )?;
 
     let reset_agent_on_done = prompt_yes_no(
         "Should the AGENT be resetted, when the environment is done after a step?",
         false,
-    );
+    )?;
 
     // SEED
-    let seed =
-        prompt_string("Seed for random number generator", None, "randomly chosen").map(Seed::from);
+    let seed = prompt_string("Seed for random number generator", None, "randomly chosen")?;
 
     // LOAD FROM
     let environment_load_path = prompt_string(
         "From which file should the ENVIRONMENT be loaded?",
         None,
         "Do not load",
-    );
+    )?;
     let agent_load_path = prompt_string(
         "From which file should the AGENT be loaded?",
         None,
         "Do not load",
-    );
+    )?;
 
     // STORE TO
     let environment_store_path = prompt_string(
         "To which file should the ENVIRONMENT be stored?",
         environment_load_path.clone(),
         "Do not store",
-    );
+    )?;
     let agent_store_path = prompt_string(
         "To which file should the AGENT be stored?",
         agent_load_path.clone(),
         "Do not store",
-    );
+    )?;
 
-    let run_options = RunOptions {
+    let run_spec = RunSpec {
+        environment: selected_environment,
+        agent: selected_agent,
+        visualiser: selected_visualiser,
+        exit_condition: selected_exit_condition,
         seed,
         reset_environment_on_done,
         reset_agent_on_done,
@@ -565,22 +872,129 @@ fn start_interactively() {
         environment_store_path,
         agent_load_path,
         agent_store_path,
+        fall_back_to_no_visualiser: false,
+        allow_unsupported_combination,
     };
 
-    start(
-        selected_environment,
-        selected_agent,
-        selected_visualiser,
-        selected_exit_condition,
-        run_options,
+    println!();
+    println!(
+        "The same run can be started with:\n{}",
+        equivalent_command_line(
+            &run_spec,
+            &environment_configuration,
+            &agent_configuration,
+            &visualiser_configuration,
+            &exit_condition_configuration,
+        )
     );
+
+    start(run_spec)
+}
+
+fn equivalent_command_line(
+    run_spec: &RunSpec,
+    environment_configuration: &HashMap<String, String>,
+    agent_configuration: &HashMap<String, String>,
+    visualiser_configuration: &HashMap<String, String>,
+    exit_condition_configuration: &HashMap<String, String>,
+) -> String {
+    fn quote(argument: &str) -> String {
+        if !argument.is_empty()
+            && argument
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "_-./=,".contains(c))
+        {
+            argument.to_string()
+        } else {
+            format!("'{}'", argument.replace('\'', "'\\''"))
+        }
+    }
+
+    let mut arguments = vec![crate_name!().to_string(), "command_line".to_string()];
+    let mut push = |name: &str, value: &str| {
+        arguments.push(name.to_string());
+        arguments.push(quote(value));
+    };
+
+    push(
+        "--environment",
+        run_spec.environment.corresponding_available().long_name(),
+    );
+    if !environment_configuration.is_empty() {
+        push(
+            "--environment-configuration",
+            &configuration_string::serialize(environment_configuration),
+        );
+    }
+    push(
+        "--agent",
+        run_spec.agent.corresponding_available().long_name(),
+    );
+    if !agent_configuration.is_empty() {
+        push(
+            "--agent-configuration",
+            &configuration_string::serialize(agent_configuration),
+        );
+    }
+    push(
+        "--visualiser",
+        run_spec.visualiser.corresponding_available().long_name(),
+    );
+    if !visualiser_configuration.is_empty() {
+        push(
+            "--visualiser-configuration",
+            &configuration_string::serialize(visualiser_configuration),
+        );
+    }
+    push(
+        "--exit-condition",
+        run_spec
+            .exit_condition
+            .corresponding_available()
+            .long_name(),
+    );
+    if !exit_condition_configuration.is_empty() {
+        push(
+            "--exit-condition-configuration",
+            &configuration_string::serialize(exit_condition_configuration),
+        );
+    }
+    if let Some(seed) = &run_spec.seed {
+        push("--seed", seed);
+    }
+    if let Some(path) = &run_spec.environment_load_path {
+        push("--environment-load-path", path);
+    }
+    if let Some(path) = &run_spec.environment_store_path {
+        push("--environment-store-path", path);
+    }
+    if let Some(path) = &run_spec.agent_load_path {
+        push("--agent-load-path", path);
+    }
+    if let Some(path) = &run_spec.agent_store_path {
+        push("--agent-store-path", path);
+    }
+    if !run_spec.reset_environment_on_done {
+        arguments.push("--not-reset-environment-on-done".to_string());
+    }
+    if run_spec.reset_agent_on_done {
+        arguments.push("--reset-agent-on-done".to_string());
+    }
+    if run_spec.fall_back_to_no_visualiser {
+        arguments.push("--fall-back-to-no-visualiser".to_string());
+    }
+    if run_spec.allow_unsupported_combination {
+        arguments.push("--allow-unsupported-combination".to_string());
+    }
+
+    arguments.join(" ")
 }
 
 pub fn prompt_string(
     prompt_text: &str,
     default: Option<String>,
     none_text: &str,
-) -> Option<String> {
+) -> Result<Option<String>, ApplicationError> {
     println!();
     println!(
         "{} (Default: {})",
@@ -591,54 +1005,49 @@ pub fn prompt_string(
         }
     );
     print!("> ");
-    std::io::stdout().flush().unwrap();
+    std::io::stdout().flush()?;
 
     let mut answer_string = String::new();
-    std::io::stdin()
-        .read_line(&mut answer_string)
-        .expect("Failed to read line");
+    std::io::stdin().read_line(&mut answer_string)?;
 
     if answer_string.trim().is_empty() {
-        default
+        Ok(default)
     } else {
-        Some(answer_string.trim().to_string())
+        Ok(Some(answer_string.trim().to_string()))
     }
 }
 
-pub fn prompt_yes_no(prompt_text: &str, default: bool) -> bool {
+pub fn prompt_yes_no(prompt_text: &str, default: bool) -> Result<bool, ApplicationError> {
     println!();
     print!(
         "{} ({}) ",
         prompt_text,
         if default { "YES/no" } else { "yes/NO" }
     );
-    std::io::stdout().flush().unwrap();
+    std::io::stdout().flush()?;
 
     let mut answer_string = String::new();
-    std::io::stdin()
-        .read_line(&mut answer_string)
-        .expect("Failed to read line");
+    std::io::stdin().read_line(&mut answer_string)?;
 
     if answer_string.trim().is_empty() {
-        default
+        Ok(default)
     } else {
-        answer_string.trim().to_lowercase().starts_with('y')
+        Ok(answer_string.trim().to_lowercase().starts_with('y'))
     }
 }
 
 fn select_interactively<S: Selected<A>, A: Clone + Available<S>, P: Fn(&A) -> bool>(
     predicate: P,
-) -> S {
+) -> Result<(S, HashMap<String, String>), ApplicationError> {
     let (available_elements, unavailable_elements): (Vec<A>, Vec<A>) =
         A::values().into_iter().partition(predicate);
     println!();
     println!("{}", A::category_headline());
     println!("{}", "-".repeat(A::category_headline().len()));
     if available_elements.is_empty() {
-        panic!(
-            "There are no {} with the previous selections!",
-            A::category_headline().to_lowercase()
-        );
+        return Err(ApplicationError::NothingAvailable(
+            A::category_headline().to_lowercase(),
+        ));
     }
 
     for (index, item) in available_elements.iter().enumerate() {
@@ -662,71 +1071,344 @@ fn select_interactively<S: Selected<A>, A: Clone + Available<S>, P: Fn(&A) -> bo
     }
 
     print!("Your choice: ");
-    std::io::stdout().flush().unwrap();
+    std::io::stdout().flush()?;
 
     let mut chosen_element_string = String::new();
-    std::io::stdin()
-        .read_line(&mut chosen_element_string)
-        .expect("Failed to read line");
-
-    usize::from_str(chosen_element_string.trim())
-        .map_err(|error| format!("{}", error))
-        .map(|index| available_elements[index].clone())
-        .or_else(|_| {
-            chosen_element_string
-                .trim()
-                .parse::<A>()
-                .map_err(|_| format!("Couldn't parse {}", chosen_element_string))
-        })
-        .and_then(|available| {
-            let configuration_options = available.available_configurations();
-            let mut chosen_configuration = HashMap::new();
-            if !configuration_options.is_empty() {
-                println!();
-                println!("There are configuration options for your choice. Please answer them.");
-                for configuration_option in configuration_options {
-                    println!();
-                    println!(
-                        "{} [{}; default: {}]",
-                        configuration_option.name,
-                        configuration_option.data_type,
-                        configuration_option.default
-                    );
-                    println!("{}", configuration_option.description);
-                    print!("Your answer: ");
-                    std::io::stdout().flush().unwrap();
-
-                    let mut answer_string = String::new();
-                    std::io::stdin()
-                        .read_line(&mut answer_string)
-                        .expect("Failed to read line");
-                    answer_string = answer_string.trim().to_string();
-                    if answer_string.is_empty() {
-                        chosen_configuration
-                            .insert(configuration_option.name, configuration_option.default);
-                    } else {
+    std::io::stdin().read_line(&mut chosen_element_string)?;
+
+    let available = usize::from_str(chosen_element_string.trim())
+        .ok()
+        .and_then(|index| available_elements.get(index).cloned())
+        .map_or_else(
+            || chosen_element_string.trim().parse::<A>(),
+            |available| Ok(available),
+        )
+        .map_err(|_| ApplicationError::InvalidChoice(chosen_element_string.trim().to_string()))?;
+
+    let configuration_options = available.available_configurations();
+    let mut chosen_configuration = HashMap::new();
+    if !configuration_options.is_empty() {
+        println!();
+        println!("There are configuration options for your choice. Please answer them.");
+        for configuration_option in configuration_options {
+            println!();
+            println!(
+                "{} [{}; default: {}]",
+                configuration_option.name,
+                configuration_option.data_type,
+                configuration_option.default
+            );
+            println!("{}", configuration_option.description);
+            loop {
+                print!("Your answer: ");
+                std::io::stdout().flush()?;
+
+                let mut answer_string = String::new();
+                std::io::stdin().read_line(&mut answer_string)?;
+                answer_string = answer_string.trim().to_string();
+                if answer_string.is_empty() {
+                    answer_string = configuration_option.default.clone();
+                }
+                match configuration_option.data_type.parse(&answer_string) {
+                    Ok(_) => {
                         chosen_configuration.insert(configuration_option.name, answer_string);
+                        break;
                     }
+                    Err(reason) => println!(
+                        "\"{}\" is not a valid {} ({}). Please try again.",
+                        answer_string, configuration_option.data_type, reason
+                    ),
                 }
             }
-            available
-                .select(chosen_configuration)
-                .map_err(|error| format!("{}", error))
-        })
-        .unwrap()
+        }
+    }
+    Ok((
+        available.select(chosen_configuration.clone())?,
+        chosen_configuration,
+    ))
+}
+
+fn check_combination(
+    selected_environment: &SelectedEnvironment,
+    selected_agent: &SelectedAgent,
+    selected_visualiser: &SelectedVisualiser,
+    selected_exit_condition: &SelectedExitCondition,
+    allow_unsupported_combination: bool,
+) -> Result<(), ApplicationError> {
+    fn unsupported<SF, AF, SS, AS>(first: &AF, second: &AS) -> Option<String>
+    where
+        SF: Selected<AF>,
+        AF: Available<SF> + AvailableSupportsAvailable<SS, AS>,
+        SS: Selected<AS>,
+        AS: Available<SS> + PartialEq,
+    {
+        if first.supports_available().contains(second) {
+            None
+        } else {
+            Some(format!(
+                "{} does not support {}",
+                first.nice_name(),
+                second.nice_name()
+            ))
+        }
+    }
+
+    let environment = selected_environment.corresponding_available();
+    let agent = selected_agent.corresponding_available();
+    let visualiser = selected_visualiser.corresponding_available();
+    let exit_condition = selected_exit_condition.corresponding_available();
+
+    let unsupported_combinations = vec![
+        unsupported(&environment, &agent),
+        unsupported(&environment, &visualiser),
+        unsupported(&environment, &exit_condition),
+        unsupported(&agent, &visualiser),
+        unsupported(&agent, &exit_condition),
+        unsupported(&visualiser, &exit_condition),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<String>>();
+
+    if unsupported_combinations.is_empty() {
+        Ok(())
+    } else if allow_unsupported_combination {
+        for unsupported_combination in unsupported_combinations {
+            eprintln!(
+                "Warning: {} (proceeding because of --allow-unsupported-combination).",
+                unsupported_combination
+            );
+        }
+        Ok(())
+    } else {
+        Err(ApplicationError::UnsupportedCombination(
+            unsupported_combinations,
+        ))
+    }
+}
+
+// Maps each environment type to the `ToActionMapper` used by the input agent. An environment
+// listed here is playable by a human without touching the dispatch in `start`.
+#[cfg(feature = "piston")]
+macro_rules! input_to_action_mapper {
+    (MountainCar) => {
+        MountainCarInputToActionMapper::default()
+    };
+    (AiLearnsToDrive) => {
+        AiLearnsToDriveInputToActionMapper::default()
+    };
+}
+
+// Each environment in `start` only names its type and how it is created. The following macros
+// dispatch over everything else.
+macro_rules! run_with_selected_agent {
+    (
+        $environment_type:ident,
+        $environment:expr,
+        $selected_agent:expr,
+        $selected_visualiser:expr,
+        $selected_exit_condition:expr,
+        $run_options:expr
+    ) => {
+        match $selected_agent {
+            SelectedAgent::Random => run_with_selected_visualiser!(
+                $environment,
+                agent: create_agent_random($environment_type::action_space()),
+                $selected_visualiser,
+                $selected_exit_condition,
+                $run_options
+            ),
+            #[cfg(feature = "piston")]
+            SelectedAgent::Input => run_with_selected_visualiser!(
+                $environment,
+                input_agent: input_to_action_mapper!($environment_type),
+                $selected_visualiser,
+                $selected_exit_condition,
+                $run_options
+            ),
+        }
+    };
+}
+
+// The `input_agent:` form creates the agent after the visualiser to use its input provider.
+macro_rules! run_with_selected_visualiser {
+    (
+        $environment:expr,
+        agent: $agent:expr,
+        $selected_visualiser:expr,
+        $selected_exit_condition:expr,
+        $run_options:expr
+    ) => {
+        match $selected_visualiser {
+            SelectedVisualiser::None => run_with_selected_exit_condition!(
+                no_visualiser: $environment,
+                $agent,
+                $selected_exit_condition,
+                $run_options
+            ),
+            #[cfg(feature = "piston")]
+            SelectedVisualiser::PistonIn2d {
+                window_title,
+                window_dimension,
+                max_frames_per_second,
+            } => run_with_selected_exit_condition!(
+                two_dimensional_visualiser: $environment,
+                $agent,
+                create_visualiser_piston_in_2d(
+                    window_title,
+                    window_dimension,
+                    max_frames_per_second,
+                ),
+                $selected_exit_condition,
+                $run_options
+            ),
+        }
+    };
+    (
+        $environment:expr,
+        input_agent: $to_action_mapper:expr,
+        $selected_visualiser:expr,
+        $selected_exit_condition:expr,
+        $run_options:expr
+    ) => {
+        match $selected_visualiser {
+            SelectedVisualiser::None => Err(ApplicationError::NoCodePath(
+                "The input agent needs a visualiser to receive input from".to_string(),
+            )),
+            #[cfg(feature = "piston")]
+            SelectedVisualiser::PistonIn2d {
+                window_title,
+                window_dimension,
+                max_frames_per_second,
+            } => {
+                let visualiser = create_visualiser_piston_in_2d(
+                    window_title,
+                    window_dimension,
+                    max_frames_per_second,
+                );
+                let agent = create_agent_input(visualiser.input_provider(), $to_action_mapper);
+                run_with_selected_exit_condition!(
+                    two_dimensional_visualiser: $environment,
+                    agent,
+                    visualiser,
+                    $selected_exit_condition,
+                    $run_options
+                )
+            }
+        }
+    };
 }
 
-fn start(
-    selected_environment: SelectedEnvironment,
-    selected_agent: SelectedAgent,
-    selected_visualiser: SelectedVisualiser,
-    selected_exit_condition: SelectedExitCondition,
-    run_options: RunOptions,
-) {
+macro_rules! run_with_selected_exit_condition {
+    (
+        no_visualiser: $environment:expr,
+        $agent:expr,
+        $selected_exit_condition:expr,
+        $run_options:expr
+    ) => {
+        match $selected_exit_condition {
+            SelectedExitCondition::EpisodesSimulated { count_of_episodes } => {
+                run_with_no_visualiser(
+                    $environment,
+                    $agent,
+                    gymnarium::exit_condition::when_no_visualiser::episodes_simulated(
+                        count_of_episodes,
+                    ),
+                    $run_options,
+                );
+                Ok(())
+            }
+            #[cfg(feature = "piston")]
+            SelectedExitCondition::VisualiserClosed => Err(ApplicationError::NoCodePath(
+                "There is no visualiser which could be closed".to_string(),
+            )),
+        }
+    };
+    (
+        two_dimensional_visualiser: $environment:expr,
+        $agent:expr,
+        $visualiser:expr,
+        $selected_exit_condition:expr,
+        $run_options:expr
+    ) => {
+        match $selected_exit_condition {
+            SelectedExitCondition::EpisodesSimulated { count_of_episodes } => {
+                run_with_two_dimensional_visualiser(
+                    $environment,
+                    $agent,
+                    $visualiser,
+                    gymnarium::exit_condition::when_visualiser::closed_or_episodes_simulated(
+                        count_of_episodes,
+                    ),
+                    $run_options,
+                );
+                Ok(())
+            }
+            #[cfg(feature = "piston")]
+            SelectedExitCondition::VisualiserClosed => {
+                run_with_two_dimensional_visualiser(
+                    $environment,
+                    $agent,
+                    $visualiser,
+                    gymnarium::exit_condition::when_visualiser::closed(),
+                    $run_options,
+                );
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "piston")]
+fn display_available() -> bool {
+    if cfg!(all(unix, not(target_os = "macos"))) {
+        ["DISPLAY", "WAYLAND_DISPLAY"]
+            .iter()
+            .any(|variable| std::env::var_os(variable).is_some_and(|value| !value.is_empty()))
+    } else {
+        true
+    }
+}
+
+#[cfg(feature = "piston")]
+fn fall_back_if_there_is_no_display(mut run_spec: RunSpec) -> Result<RunSpec, ApplicationError> {
+    if let SelectedVisualiser::PistonIn2d { .. } = run_spec.visualiser {
+        if !display_available() {
+            if run_spec.fall_back_to_no_visualiser {
+                if let SelectedAgent::Input = run_spec.agent {
+                    return Err(ApplicationError::NoDisplayForAgent(
+                        run_spec.agent.corresponding_available().nice_name(),
+                    ));
+                }
+                eprintln!(
+                    "Warning: There is no display to open the window on. Continuing without a \
+                    visualiser because of --fall-back-to-no-visualiser."
+                );
+                run_spec.visualiser = SelectedVisualiser::None;
+                if let SelectedExitCondition::VisualiserClosed = run_spec.exit_condition {
+                    run_spec.exit_condition =
+                        AvailableExitCondition::EpisodesSimulated.select(HashMap::new())?;
+                    eprintln!(
+                        "Warning: Without a visualiser there is no window to close. Exiting with \
+                        {:?} instead.",
+                        run_spec.exit_condition
+                    );
+                }
+            } else {
+                return Err(ApplicationError::NoDisplay);
+            }
+        }
+    }
+    Ok(run_spec)
+}
+
+fn start(run_spec: RunSpec) -> Result<(), ApplicationError> {
+    #[cfg(feature = "gym-envs")]
     fn create_environment_gym_mountain_car(goal_velocity: f64) -> MountainCar {
         MountainCar::new(goal_velocity)
     }
 
+    #[cfg(feature = "code-bullet-envs")]
     fn create_environment_code_bullet_ai_learns_to_drive(
         sensor_lines_visible: bool,
         track_visible: bool,
@@ -743,6 +1425,7 @@ fn start(
         RandomAgent::with(action_spaces)
     }
 
+    #[cfg(feature = "piston")]
     fn create_agent_input<
         IP: InputProvider,
         TAMError: Error,
@@ -754,6 +1437,7 @@ fn start(
         InputAgent::new(input_provider, to_action_mapper)
     }
 
+    #[cfg(feature = "piston")]
     fn create_visualiser_piston_in_2d(
         window_title: String,
         window_dimension: (u32, u32),
@@ -762,6 +1446,28 @@ fn start(
         PistonVisualiser::run(window_title, window_dimension, max_frames_per_second)
     }
 
+    #[cfg(feature = "piston")]
+    let run_spec = fall_back_if_there_is_no_display(run_spec)?;
+
+    check_combination(
+        &run_spec.environment,
+        &run_spec.agent,
+        &run_spec.visualiser,
+        &run_spec.exit_condition,
+        run_spec.allow_unsupported_combination,
+    )?;
+
+    crash_report::set_current_run_spec(&run_spec);
+
+    let run_options = run_spec.run_options();
+    let RunSpec {
+        environment: selected_environment,
+        agent: selected_agent,
+        visualiser: selected_visualiser,
+        exit_condition: selected_exit_condition,
+        ..
+    } = run_spec;
+
     println!(
         "Starting environment {:?} with agent {:?} within visualiser {:?} and exit condition {:?} \
         using {}, {}resetting environment when environment is done and {}resetting agent when environment is \
@@ -803,202 +1509,51 @@ fn start(
         },
     );
 
-    match selected_environment {
-        SelectedEnvironment::GymMountainCar { goal_velocity } => match selected_agent {
-            SelectedAgent::Random => match selected_visualiser {
-                SelectedVisualiser::None => match selected_exit_condition {
-                    SelectedExitCondition::EpisodesSimulated { count_of_episodes } => {
-                        run_with_no_visualiser(
-                            create_environment_gym_mountain_car(goal_velocity),
-                            create_agent_random(MountainCar::action_space()),
-                            gymnarium::exit_condition::when_no_visualiser::episodes_simulated(count_of_episodes),
-                            run_options,
-                        )
-                    }
-                    SelectedExitCondition::VisualiserClosed => panic!(),
-                },
-                SelectedVisualiser::PistonIn2d {
-                    window_title,
-                    window_dimension,
-                    max_frames_per_second,
-                } => match selected_exit_condition {
-                    SelectedExitCondition::EpisodesSimulated { count_of_episodes } => {
-                        run_with_two_dimensional_visualiser(
-                            create_environment_gym_mountain_car(goal_velocity),
-                            create_agent_random(MountainCar::action_space()),
-                            create_visualiser_piston_in_2d(
-                                window_title,
-                                window_dimension,
-                                max_frames_per_second,
-                            ),
-                            gymnarium::exit_condition::when_visualiser::closed_or_episodes_simulated(count_of_episodes),
-                            run_options,
-                        )
-                    }
-                    SelectedExitCondition::VisualiserClosed => run_with_two_dimensional_visualiser(
-                        create_environment_gym_mountain_car(goal_velocity),
-                        create_agent_random(MountainCar::action_space()),
-                        create_visualiser_piston_in_2d(
-                            window_title,
-                            window_dimension,
-                            max_frames_per_second,
-                        ),
-                        gymnarium::exit_condition::when_visualiser::closed(),
-                        run_options,
-                    ),
-                },
-            },
-            SelectedAgent::Input => match selected_visualiser {
-                SelectedVisualiser::None => panic!(),
-                SelectedVisualiser::PistonIn2d {
-                    window_title,
-                    window_dimension,
-                    max_frames_per_second,
-                } => match selected_exit_condition {
-                    SelectedExitCondition::EpisodesSimulated { count_of_episodes } => {
-                        let visualiser = create_visualiser_piston_in_2d(
-                            window_title,
-                            window_dimension,
-                            max_frames_per_second,
-                        );
-                        run_with_two_dimensional_visualiser(
-                            create_environment_gym_mountain_car(goal_velocity),
-                            create_agent_input(
-                                visualiser.input_provider(),
-                                MountainCarInputToActionMapper::default(),
-                            ),
-                            visualiser,
-                            gymnarium::exit_condition::when_visualiser::closed_or_episodes_simulated(count_of_episodes),
-                            run_options,
-                        );
-                    }
-                    SelectedExitCondition::VisualiserClosed => {
-                        let visualiser = create_visualiser_piston_in_2d(
-                            window_title,
-                            window_dimension,
-                            max_frames_per_second,
-                        );
-                        run_with_two_dimensional_visualiser(
-                            create_environment_gym_mountain_car(goal_velocity),
-                            create_agent_input(
-                                visualiser.input_provider(),
-                                MountainCarInputToActionMapper::default(),
-                            ),
-                            visualiser,
-                            gymnarium::exit_condition::when_visualiser::closed(),
-                            run_options,
-                        );
-                    }
-                },
-            },
-        },
+    let started_at = Instant::now();
+
+    let result = match selected_environment {
+        #[cfg(feature = "gym-envs")]
+        SelectedEnvironment::GymMountainCar { goal_velocity } => run_with_selected_agent!(
+            MountainCar,
+            create_environment_gym_mountain_car(goal_velocity),
+            selected_agent,
+            selected_visualiser,
+            selected_exit_condition,
+            run_options
+        ),
+        #[cfg(feature = "code-bullet-envs")]
         SelectedEnvironment::CodeBulletAiLearnsToDrive {
             track_visible,
             sensor_lines_visible,
             car_sensor_distance,
-        } => match selected_agent {
-            SelectedAgent::Random => match selected_visualiser {
-                SelectedVisualiser::None => match selected_exit_condition {
-                    SelectedExitCondition::EpisodesSimulated { count_of_episodes } => {
-                        run_with_no_visualiser(
-                            create_environment_code_bullet_ai_learns_to_drive(
-                                sensor_lines_visible,
-                                track_visible,
-                                car_sensor_distance,
-                            ),
-                            create_agent_random(AiLearnsToDrive::action_space()),
-                            gymnarium::exit_condition::when_no_visualiser::episodes_simulated(count_of_episodes),
-                            run_options,
-                        )
-                    }
-                    SelectedExitCondition::VisualiserClosed => panic!(),
-                },
-                SelectedVisualiser::PistonIn2d {
-                    window_title,
-                    window_dimension,
-                    max_frames_per_second,
-                } => match selected_exit_condition {
-                    SelectedExitCondition::EpisodesSimulated { count_of_episodes } => {
-                        run_with_two_dimensional_visualiser(
-                            create_environment_code_bullet_ai_learns_to_drive(
-                                sensor_lines_visible,
-                                track_visible,
-                                car_sensor_distance,
-                            ),
-                            create_agent_random(AiLearnsToDrive::action_space()),
-                            create_visualiser_piston_in_2d(
-                                window_title,
-                                window_dimension,
-                                max_frames_per_second,
-                            ),
-                            gymnarium::exit_condition::when_visualiser::closed_or_episodes_simulated(count_of_episodes),
-                            run_options,
-                        )
-                    }
-                    SelectedExitCondition::VisualiserClosed => run_with_two_dimensional_visualiser(
-                        create_environment_code_bullet_ai_learns_to_drive(
-                            sensor_lines_visible,
-                            track_visible,
-                            car_sensor_distance,
-                        ),
-                        create_agent_random(AiLearnsToDrive::action_space()),
-                        create_visualiser_piston_in_2d(
-                            window_title,
-                            window_dimension,
-                            max_frames_per_second,
-                        ),
-                        gymnarium::exit_condition::when_visualiser::closed(),
-                        run_options,
-                    ),
-                },
-            },
-            SelectedAgent::Input => {
-                match selected_visualiser {
-                    SelectedVisualiser::None => panic!(),
-                    SelectedVisualiser::PistonIn2d {
-                        window_title,
-                        window_dimension,
-                        max_frames_per_second,
-                    } => {
-                        match selected_exit_condition {
-                            SelectedExitCondition::EpisodesSimulated { count_of_episodes } => {
-                                let visualiser = create_visualiser_piston_in_2d(
-                                    window_title,
-                                    window_dimension,
-                                    max_frames_per_second,
-                                );
-                                run_with_two_dimensional_visualiser(
-                            create_environment_code_bullet_ai_learns_to_drive(sensor_lines_visible, track_visible, car_sensor_distance),
-                            create_agent_input(
-                                visualiser.input_provider(),
-                                AiLearnsToDriveInputToActionMapper::default(),
-                            ),
-                            visualiser,
-                            gymnarium::exit_condition::when_visualiser::closed_or_episodes_simulated(count_of_episodes),
-                            run_options,
-                        );
-                            }
-                            SelectedExitCondition::VisualiserClosed => {
-                                let visualiser = create_visualiser_piston_in_2d(
-                                    window_title,
-                                    window_dimension,
-                                    max_frames_per_second,
-                                );
-                                run_with_two_dimensional_visualiser(
-                            create_environment_code_bullet_ai_learns_to_drive(sensor_lines_visible, track_visible, car_sensor_distance),
-                            create_agent_input(
-                                visualiser.input_provider(),
-                                AiLearnsToDriveInputToActionMapper::default(),
-                            ),
-                            visualiser,
-                            gymnarium::exit_condition::when_visualiser::closed(),
-                            run_options,
-                        );
-                            }
-                        }
-                    }
-                }
-            }
-        },
+        } => run_with_selected_agent!(
+            AiLearnsToDrive,
+            create_environment_code_bullet_ai_learns_to_drive(
+                sensor_lines_visible,
+                track_visible,
+                car_sensor_distance,
+            ),
+            selected_agent,
+            selected_visualiser,
+            selected_exit_condition,
+            run_options
+        ),
+    };
+
+    if result.is_ok() {
+        crash_report::clear_current_run_spec();
+        match ResourceUsage::of_this_process() {
+            Some(resource_usage) => println!(
+                "Finished after a wall time of {:.3} seconds with {}.",
+                started_at.elapsed().as_secs_f64(),
+                resource_usage
+            ),
+            None => println!(
+                "Finished after a wall time of {:.3} seconds.",
+                started_at.elapsed().as_secs_f64()
+            ),
+        }
     }
+
+    result
 }
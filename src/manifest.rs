@@ -0,0 +1,49 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ApplicationError;
+use crate::run_spec::RunSpec;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Manifest {
+    pub application_version: String,
+    pub started_at: u64,
+    pub finished_at: Option<u64>,
+    pub run_spec: RunSpec,
+}
+
+impl Manifest {
+    pub fn new(run_spec: RunSpec) -> Self {
+        Self {
+            application_version: env!("CARGO_PKG_VERSION").to_string(),
+            started_at: seconds_since_epoch(),
+            finished_at: None,
+            run_spec: run_spec.with_chosen_seed(),
+        }
+    }
+
+    pub fn finish(&mut self) {
+        self.finished_at = Some(seconds_since_epoch());
+    }
+
+    pub fn load(path: &str) -> Result<Self, ApplicationError> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|error| ApplicationError::ManifestIo(path.to_string(), error))?;
+        serde_json::from_str(&content)
+            .map_err(|error| ApplicationError::ManifestFormat(path.to_string(), error))
+    }
+
+    pub fn store(&self, path: &str) -> Result<(), ApplicationError> {
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|error| ApplicationError::ManifestFormat(path.to_string(), error))?;
+        std::fs::write(path, content)
+            .map_err(|error| ApplicationError::ManifestIo(path.to_string(), error))
+    }
+}
+
+fn seconds_since_epoch() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
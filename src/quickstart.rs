@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use crate::availables::*;
+use crate::error::ApplicationError;
+use crate::run_spec::RunSpec;
+
+pub struct Preset {
+    pub description: &'static str,
+    pub environment: AvailableEnvironment,
+    pub agent: AvailableAgent,
+    pub visualiser: AvailableVisualiser,
+    pub exit_condition: AvailableExitCondition,
+}
+
+impl Preset {
+    // Every component uses its default configuration, so the equivalent command line only has to
+    // name them.
+    pub fn run_spec(&self) -> Result<RunSpec, ApplicationError> {
+        Ok(RunSpec {
+            environment: self.environment.clone().select(HashMap::new())?,
+            agent: self.agent.clone().select(HashMap::new())?,
+            visualiser: self.visualiser.clone().select(HashMap::new())?,
+            exit_condition: self.exit_condition.clone().select(HashMap::new())?,
+            seed: None,
+            reset_environment_on_done: true,
+            reset_agent_on_done: false,
+            environment_load_path: None,
+            environment_store_path: None,
+            agent_load_path: None,
+            agent_store_path: None,
+            fall_back_to_no_visualiser: false,
+            allow_unsupported_combination: false,
+        })
+    }
+}
+
+impl Preset {
+    pub fn can_open_visualiser(&self) -> bool {
+        match self.visualiser {
+            AvailableVisualiser::None => true,
+            #[cfg(feature = "piston")]
+            AvailableVisualiser::PistonIn2d => crate::display_available(),
+        }
+    }
+}
+
+pub fn presets() -> Vec<Preset> {
+    vec![
+        #[cfg(all(feature = "code-bullet-envs", feature = "piston"))]
+        Preset {
+            description: "Watch a random agent drive",
+            environment: AvailableEnvironment::CodeBulletAiLearnsToDrive,
+            agent: AvailableAgent::Random,
+            visualiser: AvailableVisualiser::PistonIn2d,
+            exit_condition: AvailableExitCondition::VisualiserClosed,
+        },
+        #[cfg(all(feature = "code-bullet-envs", feature = "piston"))]
+        Preset {
+            description: "Play the driving game yourself",
+            environment: AvailableEnvironment::CodeBulletAiLearnsToDrive,
+            agent: AvailableAgent::Input,
+            visualiser: AvailableVisualiser::PistonIn2d,
+            exit_condition: AvailableExitCondition::VisualiserClosed,
+        },
+        #[cfg(all(feature = "gym-envs", feature = "piston"))]
+        Preset {
+            description: "Watch a random agent try to get the car up the mountain",
+            environment: AvailableEnvironment::GymMountainCar,
+            agent: AvailableAgent::Random,
+            visualiser: AvailableVisualiser::PistonIn2d,
+            exit_condition: AvailableExitCondition::VisualiserClosed,
+        },
+        #[cfg(all(feature = "gym-envs", feature = "piston"))]
+        Preset {
+            description: "Get the car up the mountain yourself",
+            environment: AvailableEnvironment::GymMountainCar,
+            agent: AvailableAgent::Input,
+            visualiser: AvailableVisualiser::PistonIn2d,
+            exit_condition: AvailableExitCondition::VisualiserClosed,
+        },
+        #[cfg(feature = "gym-envs")]
+        Preset {
+            description: "Simulate 20 episodes of MountainCar without a window",
+            environment: AvailableEnvironment::GymMountainCar,
+            agent: AvailableAgent::Random,
+            visualiser: AvailableVisualiser::None,
+            exit_condition: AvailableExitCondition::EpisodesSimulated,
+        },
+        #[cfg(all(feature = "code-bullet-envs", not(feature = "gym-envs")))]
+        Preset {
+            description: "Simulate 20 episodes of the driving game without a window",
+            environment: AvailableEnvironment::CodeBulletAiLearnsToDrive,
+            agent: AvailableAgent::Random,
+            visualiser: AvailableVisualiser::None,
+            exit_condition: AvailableExitCondition::EpisodesSimulated,
+        },
+    ]
+}
@@ -0,0 +1,48 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct ResourceUsage {
+    pub peak_memory_kilobytes: u64,
+    pub cpu_time_seconds: f64,
+}
+
+impl ResourceUsage {
+    // Covers the whole process, which only ever executes a single run.
+    #[cfg(unix)]
+    pub fn of_this_process() -> Option<Self> {
+        let mut usage = std::mem::MaybeUninit::<libc::rusage>::uninit();
+        if unsafe { libc::getrusage(libc::RUSAGE_SELF, usage.as_mut_ptr()) } != 0 {
+            return None;
+        }
+        let usage = unsafe { usage.assume_init() };
+        let seconds = |time: libc::timeval| time.tv_sec as f64 + time.tv_usec as f64 / 1_000_000.0;
+        // macOS reports the maximum resident set size in bytes, everyone else in kilobytes.
+        let peak_memory_kilobytes = if cfg!(target_os = "macos") {
+            usage.ru_maxrss as u64 / 1024
+        } else {
+            usage.ru_maxrss as u64
+        };
+        Some(Self {
+            peak_memory_kilobytes,
+            cpu_time_seconds: seconds(usage.ru_utime) + seconds(usage.ru_stime),
+        })
+    }
+
+    #[cfg(not(unix))]
+    pub fn of_this_process() -> Option<Self> {
+        None
+    }
+}
+
+impl Display for ResourceUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "a CPU time of {:.3} seconds and a peak memory usage of {:.1} MiB",
+            self.cpu_time_seconds,
+            self.peak_memory_kilobytes as f64 / 1024.0
+        )
+    }
+}
@@ -0,0 +1,78 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use gymnarium::gymnarium_base::Seed;
+use gymnarium::RunOptions;
+
+use crate::availables::*;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RunSpec {
+    pub environment: SelectedEnvironment,
+    pub agent: SelectedAgent,
+    pub visualiser: SelectedVisualiser,
+    pub exit_condition: SelectedExitCondition,
+    pub seed: Option<String>,
+    pub reset_environment_on_done: bool,
+    pub reset_agent_on_done: bool,
+    pub environment_load_path: Option<String>,
+    pub environment_store_path: Option<String>,
+    pub agent_load_path: Option<String>,
+    pub agent_store_path: Option<String>,
+    pub fall_back_to_no_visualiser: bool,
+    pub allow_unsupported_combination: bool,
+}
+
+impl RunSpec {
+    pub fn run_options(&self) -> RunOptions {
+        RunOptions {
+            seed: self.seed.as_deref().map(Seed::from),
+            reset_environment_on_done: self.reset_environment_on_done,
+            reset_agent_on_done: self.reset_agent_on_done,
+            environment_load_path: self.environment_load_path.clone(),
+            environment_store_path: self.environment_store_path.clone(),
+            agent_load_path: self.agent_load_path.clone(),
+            agent_store_path: self.agent_store_path.clone(),
+        }
+    }
+    // Without a given seed gymnarium picks one which can't be recorded, so one is chosen here.
+    pub fn with_chosen_seed(self) -> Self {
+        Self {
+            seed: self.seed.or_else(|| {
+                Some(format!(
+                    "{}",
+                    SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .map_or(0, |duration| duration.as_nanos())
+                ))
+            }),
+            ..self
+        }
+    }
+
+    // Runs a single episode as fast as possible without overwriting any stored files.
+    pub fn into_smoke_test(self) -> Self {
+        Self {
+            visualiser: match self.visualiser {
+                #[cfg(feature = "piston")]
+                SelectedVisualiser::PistonIn2d {
+                    window_title,
+                    window_dimension,
+                    ..
+                } => SelectedVisualiser::PistonIn2d {
+                    window_title,
+                    window_dimension,
+                    max_frames_per_second: None,
+                },
+                visualiser => visualiser,
+            },
+            exit_condition: SelectedExitCondition::EpisodesSimulated {
+                count_of_episodes: 1,
+            },
+            environment_store_path: None,
+            agent_store_path: None,
+            ..self
+        }
+    }
+}